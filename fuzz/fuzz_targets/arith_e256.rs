@@ -1,80 +1,100 @@
 #![no_main]
-use eint::{Eint, E256};
+use eint::{Eint, Narrow, Widen, E16, E2048, E256, E512, E8};
 use libfuzzer_sys::fuzz_target;
-use uint::construct_uint;
+use primitive_types::{U256, U512};
 
-construct_uint! {
-    pub struct U256(4);
-}
-
-impl std::convert::From<E256> for U256 {
-    fn from(e: E256) -> Self {
-        let mut buf = [0u8; 32];
-        e.put(&mut buf);
-        U256::from_little_endian(&buf)
-    }
-}
-
-impl std::convert::From<U256> for E256 {
-    fn from(u: U256) -> Self {
-        let mut buf = [0u8; 32];
-        u.to_little_endian(&mut buf);
-        E256::get(&buf)
-    }
-}
-
-fn test_and(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_and(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let ru = u0 & u1;
     let re = e0 & e1;
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_bit(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let i = E256::get(&data[0x20..0x40]).u32() % 256;
+fn test_bit(e0: E256, _e1: E256, n: u8) {
+    let u0 = U256::from(e0);
+    let i = n as u32;
     let ru = u0.bit(i as usize);
     let re = e0.bit(i);
     assert_eq!(ru, re);
 }
 
-fn test_bit_clr(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let mut e0 = E256::get(&data[0x00..0x20]);
-    let i = E256::get(&data[0x20..0x40]).u32() % 256;
+fn test_bit_clr(mut e0: E256, _e1: E256, n: u8) {
+    let u0 = U256::from(e0);
+    let i = n as u32;
     let ru = u0 & !(U256::one() << i);
     e0.bit_clr(i);
     let re = e0;
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_bit_set(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let mut e0 = E256::get(&data[0x00..0x20]);
-    let i = E256::get(&data[0x20..0x40]).u32() % 256;
+fn test_bit_set(mut e0: E256, _e1: E256, n: u8) {
+    let u0 = U256::from(e0);
+    let i = n as u32;
     let ru = u0 | (U256::one() << i);
     e0.bit_set(i);
     let re = e0;
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_clz(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let e0 = E256::get(&data[0x00..0x20]);
+fn test_borrowing_sub(e0: E256, e1: E256, n: u8) {
+    let u0 = U512::from(E512::from(e0));
+    let u1 = U512::from(E512::from(e1));
+    let borrow = n & 1 != 0;
+    let (ru, _) = u0.overflowing_sub(u1 + U512::from(borrow as u8));
+    let (lo, hi) = E512::from(ru).split();
+    let re = e0.borrowing_sub(e1, borrow);
+    assert_eq!(re, (lo, hi != E256::ZERO));
+}
+
+fn test_brev8(e0: E256, _e1: E256) {
+    let mut buf = [0u8; 32];
+    e0.put(&mut buf);
+    let buf = buf.map(|e| e.reverse_bits());
+    let ru = U256::from_little_endian(&buf);
+    let re = e0.brev8();
+    assert_eq!(Into::<E256>::into(ru), re);
+}
+
+fn test_carrying_add(e0: E256, e1: E256, n: u8) {
+    let u0 = U512::from(E512::from(e0));
+    let u1 = U512::from(E512::from(e1));
+    let carry = n & 1 != 0;
+    let ru = u0 + u1 + U512::from(carry as u8);
+    let (lo, hi) = E512::from(ru).split();
+    let re = e0.carrying_add(e1, carry);
+    assert_eq!(re, (lo, hi != E256::ZERO));
+}
+
+fn test_clmul(e0: E256, e1: E256) {
+    let u0 = U512::from(E512::from(e0));
+    let u1 = U512::from(E512::from(e1));
+    let mut ru = U512::zero();
+    for i in 0..256 {
+        if u1.bit(i) {
+            ru = ru ^ (u0 << i);
+        }
+    }
+    let (lo, hi) = e0.widening_clmul(e1);
+    assert_eq!(E512::from(ru).split(), (lo, hi));
+    assert_eq!(e0.clmul(e1), lo);
+    assert_eq!(e0.clmulh(e1), hi);
+    let (x, y) = (e0.split().0, e1.split().0);
+    let (lo, hi) = x.widening_clmul(y);
+    let r = E256::from(x).clmul(E256::from(y));
+    assert_eq!(E256::from(lo) | E256::from(hi) << 128, r);
+}
+
+fn test_clz(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
     let ru = u0.leading_zeros();
     let re = e0.clz();
     assert_eq!(ru, re);
 }
 
-fn test_cmp_u(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_cmp_u(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     assert_eq!(u0 < u1, e0 < e1);
     assert_eq!(u0 > u1, e0 > e1);
     assert_eq!(u0 <= u1, e0 <= e1);
@@ -83,150 +103,452 @@ fn test_cmp_u(data: &[u8]) {
     assert_eq!(u0 != u1, e0 != e1);
 }
 
-fn test_ctz(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let e0 = E256::get(&data[0x00..0x20]);
+fn test_cpop_under(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
+    let ru = (0..256).filter(|&i| u0.bit(i) && u1.bit(i)).count() as u32;
+    let re = e0.cpop_under(&e1);
+    assert_eq!(ru, re);
+}
+
+fn test_ct(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
+    assert_eq!(e0.ct_eq(&e1), (u0 == u1) as u8);
+    assert_eq!(e0.ct_eq(&e0), 1);
+    assert_eq!(e0.ct_lt_s(&e1), (e0.cmp_s(&e1) == std::cmp::Ordering::Less) as u8);
+    assert_eq!(e0.ct_lt_u(&e1), (u0 < u1) as u8);
+    assert_eq!(E256::ct_select(1, e0, e1), e0);
+    assert_eq!(E256::ct_select(0, e0, e1), e1);
+    let (mut a, mut b) = (e0, e1);
+    a.ct_swap(&mut b, 0);
+    assert_eq!((a, b), (e0, e1));
+    a.ct_swap(&mut b, 1);
+    assert_eq!((a, b), (e1, e0));
+    assert_eq!(e0.ct_add(e1), e0.wrapping_add(e1));
+    assert_eq!(e0.ct_sub(e1), e0.wrapping_sub(e1));
+    assert_eq!(e0.ct_mul(e1), e0.wrapping_mul(e1));
+    let (w0, w1) = (E2048::from(e0) << 1000u32 | E2048::from(e1), E2048::from(e1) << 1500u32 | E2048::from(e0));
+    assert_eq!(w0.ct_add(w1), w0.wrapping_add(w1));
+    assert_eq!(w0.ct_sub(w1), w0.wrapping_sub(w1));
+    assert_eq!(w0.ct_mul(w1), w0.wrapping_mul(w1));
+}
+
+fn test_ctz(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
     let ru = u0.trailing_zeros();
     let re = e0.ctz();
     assert_eq!(ru, re);
 }
 
-fn test_not(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let e0 = E256::get(&data[0x00..0x20]);
+fn test_extract_bits(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let start = e1.u32() % 272;
+    let len = (e1.u64() >> 32) as u32 % 272;
+    let mut ru = U256::zero();
+    for i in 0..len.min(256) {
+        if start + i < 256 && u0.bit((start + i) as usize) {
+            ru = ru | (U256::one() << i);
+        }
+    }
+    let re = e0.extract_bits(start, len);
+    assert_eq!(Into::<E256>::into(ru), re);
+}
+
+fn test_first_set(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let ru = (0..256).find(|&i| u0.bit(i as usize));
+    let re = e0.first_set();
+    assert_eq!(ru, re);
+}
+
+fn test_hamming_distance(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
+    let x = u0 ^ u1;
+    let ru = (0..256).filter(|&i| x.bit(i)).count() as u32;
+    let re = e0.hamming_distance(&e1);
+    assert_eq!(ru, re);
+}
+
+fn test_highest_set_bit(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let ru = if u0.is_zero() { None } else { Some(u0.bits() as u32 - 1) };
+    let re = e0.highest_set_bit();
+    assert_eq!(ru, re);
+}
+
+fn test_insert_bits(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
+    let start = e1.u32() % 272;
+    let len = (e1.u64() >> 32) as u32 % 272;
+    let mut ru = u0;
+    for i in 0..len.min(256) {
+        if start + i < 256 {
+            let b = U256::one() << (start + i);
+            ru = if u1.bit(i as usize) { ru | b } else { ru & !b };
+        }
+    }
+    let re = e0.insert_bits(start, len, e1);
+    assert_eq!(Into::<E256>::into(ru), re);
+}
+
+fn test_iota(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let mut ru = Vec::new();
+    let mut n = 0;
+    for i in 0..256 {
+        ru.push(n);
+        if u0.bit(i) {
+            n += 1;
+        }
+    }
+    let re: Vec<u32> = e0.iota().collect();
+    assert_eq!(ru, re);
+}
+
+fn test_iter_ones(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let ru: Vec<u32> = (0..256).filter(|&i| u0.bit(i as usize)).collect();
+    let re: Vec<u32> = e0.iter_ones().collect();
+    assert_eq!(ru, re);
+    assert_eq!(e0.iter_ones().len(), ru.len());
+}
+
+fn test_lanes(e0: E256, e1: E256, n: u8) {
+    let b = |f: fn(u8, u8) -> u8| {
+        let r: Vec<u8> = (0..32).map(|i| f(e0.lane(i), e1.lane(i))).collect();
+        E256::get(&r)
+    };
+    assert_eq!(e0.lanes_add::<E8>(e1), b(u8::wrapping_add));
+    assert_eq!(e0.lanes_sub::<E8>(e1), b(u8::wrapping_sub));
+    assert_eq!(e0.lanes_max_u::<E8>(e1), b(u8::max));
+    assert_eq!(e0.lanes_min_s::<E8>(e1), b(|p, q| (p as i8).min(q as i8) as u8));
+    let n = n as u32;
+    let r: Vec<u8> = e0.lanes::<u8>().map(|p| p.wrapping_shl(n)).collect();
+    assert_eq!(e0.lanes_shl::<E8>(n), E256::get(&r));
+    let mut m = E256::ZERO;
+    for i in 0..32 {
+        if e0.lane::<u8>(i) == e1.lane::<u8>(i) {
+            m.bit_set(i as u32);
+        }
+    }
+    assert_eq!(e0.lanes_cmp_eq::<E8>(e1), m);
+    assert_eq!(e0.lanes_cmp_eq::<E8>(e0), E256::from(u32::MAX));
+    let h = |f: fn(u16, u16) -> u16| {
+        let mut r = E256::ZERO;
+        for i in 0..16 {
+            r.set_lane(i, f(e0.lane(i), e1.lane(i)));
+        }
+        r
+    };
+    assert_eq!(e0.lanes_add::<E16>(e1), h(u16::wrapping_add));
+    assert_eq!(e0.lanes_sub::<E16>(e1), h(u16::wrapping_sub));
+    assert_eq!(e0.lanes_min_u::<E16>(e1), h(u16::min));
+    assert_eq!(e0.lanes_max_s::<E16>(e1), h(|p, q| (p as i16).max(q as i16) as u16));
+}
+
+fn test_leading_ones(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let ru = (!u0).leading_zeros();
+    let re = e0.leading_ones();
+    assert_eq!(ru, re);
+}
+
+fn test_leading_sign_bits(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let ru = if u0.bit(255) { (!u0).leading_zeros() - 1 } else { u0.leading_zeros() - 1 };
+    let re = e0.leading_sign_bits();
+    assert_eq!(ru, re);
+}
+
+fn test_lowest_set_bit(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let ru = if u0.is_zero() { None } else { Some(u0.trailing_zeros()) };
+    let re = e0.lowest_set_bit();
+    assert_eq!(ru, re);
+}
+
+fn test_mul_add(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
+    let (ru, _) = u0.overflowing_mul(u1);
+    let (ru, _) = ru.overflowing_add(u1);
+    assert_eq!(Into::<E256>::into(ru), e0.mul_add(e1, e1));
+    let (ru, _) = u0.overflowing_mul(u1);
+    let (ru, _) = u1.overflowing_sub(ru);
+    assert_eq!(Into::<E256>::into(ru), e0.mul_sub_neg(e1, e1));
+}
+
+fn test_not(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
     let ru = !u0;
     let re = !e0;
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_or(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_or(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let ru = u0 | u1;
     let re = e0 | e1;
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_saturating_add(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_parity(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let ru = (0..256).filter(|&i| u0.bit(i)).count() % 2 == 1;
+    let re = e0.parity();
+    assert_eq!(ru, re);
+}
+
+fn test_pdep(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
+    let mut ru = U256::zero();
+    let mut k = 0;
+    for i in 0..256 {
+        if u1.bit(i) {
+            if u0.bit(k) {
+                ru = ru | (U256::one() << i);
+            }
+            k += 1;
+        }
+    }
+    let re = e0.pdep(e1);
+    assert_eq!(Into::<E256>::into(ru), re);
+}
+
+fn test_pext(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
+    let mut ru = U256::zero();
+    let mut k = 0;
+    for i in 0..256 {
+        if u1.bit(i) {
+            if u0.bit(i) {
+                ru = ru | (U256::one() << k);
+            }
+            k += 1;
+        }
+    }
+    let re = e0.pext(e1);
+    assert_eq!(Into::<E256>::into(ru), re);
+}
+
+fn test_reverse_bits(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let mut ru = U256::zero();
+    for i in 0..256 {
+        if u0.bit(i) {
+            ru = ru | (U256::one() << (255 - i));
+        }
+    }
+    let re = e0.reverse_bits();
+    assert_eq!(Into::<E256>::into(ru), re);
+}
+
+fn test_rotate_left(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let n = e1.u32() % 256;
+    let ru = if n == 0 { u0 } else { (u0 << n) | (u0 >> (256 - n)) };
+    let re = e0.rotate_left(e1.u32());
+    assert_eq!(Into::<E256>::into(ru), re);
+}
+
+fn test_rotate_right(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let n = e1.u32() % 256;
+    let ru = if n == 0 { u0 } else { (u0 >> n) | (u0 << (256 - n)) };
+    let re = e0.rotate_right(e1.u32());
+    assert_eq!(Into::<E256>::into(ru), re);
+}
+
+fn test_saturating_add(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let ru = u0.saturating_add(u1);
     let (re, _) = e0.saturating_add_u(e1);
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_saturating_sub(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_saturating_sub(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let ru = u0.saturating_sub(u1);
     let (re, _) = e0.saturating_sub_u(e1);
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_wrapping_add(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_set_first(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let (sbf, sif, sof) = match (0..256).find(|&i| u0.bit(i)) {
+        Some(i) => ((U256::one() << i) - 1, ((U256::one() << i) - 1) | (U256::one() << i), U256::one() << i),
+        None => (U256::MAX, U256::MAX, U256::zero()),
+    };
+    assert_eq!(Into::<E256>::into(sbf), e0.set_before_first());
+    assert_eq!(Into::<E256>::into(sif), e0.set_including_first());
+    assert_eq!(Into::<E256>::into(sof), e0.set_only_first());
+}
+
+fn test_swap_bytes(e0: E256, _e1: E256) {
+    let mut buf = [0u8; 32];
+    e0.put(&mut buf);
+    let u0 = U256::from_big_endian(&buf);
+    let re = e0.swap_bytes();
+    assert_eq!(Into::<E256>::into(u0), re);
+}
+
+fn test_trailing_ones(e0: E256, _e1: E256) {
+    let u0 = U256::from(e0);
+    let ru = (!u0).trailing_zeros();
+    let re = e0.trailing_ones();
+    assert_eq!(ru, re);
+}
+
+fn test_wrapping_add(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let (ru, _) = u0.overflowing_add(u1);
     let re = e0.wrapping_add(e1);
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_wrapping_div_u(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_wrapping_div_u(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let ru = if u1.is_zero() { U256::MAX } else { u0 / u1 };
     let re = e0 / e1;
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_wrapping_mul(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_widen(e0: E256, e1: E256) {
+    let u0 = U512::from(E512::from(e0));
+    let u1 = U512::from(E512::from(e1));
+    let into = E512::from;
+    let wide = E256::concat(e0, e1);
+    assert_eq!(wide, E512::from(e0) | E512::from(e1) << 256u32);
+    assert_eq!(wide.split(), (e0, e1));
+    assert_eq!(e0.widen_u(), into(u0));
+    assert_eq!(e0.add_wide_u(e1), into(u0 + u1));
+    assert_eq!(e0.mul_wide_u(e1), into(u0 * u1));
+    assert_eq!(e0.sub_wide_u(e1), into(u0.overflowing_sub(u1).0));
+    assert_eq!(E256::add_wv_u(wide, e1), into(U512::from(wide).overflowing_add(u1).0));
+    assert_eq!(E256::sub_wv_u(wide, e1), into(U512::from(wide).overflowing_sub(u1).0));
+    assert_eq!(e0.mul_wide_s(e1), E256::concat(e0.widening_mul_s(e1).0, e0.widening_mul_s(e1).1));
+    assert_eq!(e0.widen_s(), E512::from(e0).sext(255));
+}
+
+fn test_widening_mul_add(e0: E256, e1: E256) {
+    let u0 = U512::from(E512::from(e0));
+    let u1 = U512::from(E512::from(e1));
+    let (ru, _) = (u0 * u1).overflowing_add(U512::from(E512::from(e1) | E512::from(e0) << 256u32));
+    let re = e0.widening_mul_add_u(e1, e1, e0);
+    assert_eq!(re, E512::from(ru).split());
+    let add = |(lo, hi): (E256, E256)| {
+        let (lo, carry) = lo.carrying_add(e1, false);
+        (lo, hi.carrying_add(e0, carry).0)
+    };
+    assert_eq!(e0.widening_mul_add_s(e1, e1, e0), add(e0.widening_mul_s(e1)));
+    assert_eq!(e0.widening_mul_add_su(e1, e1, e0), add(e0.widening_mul_su(e1)));
+    assert_eq!(e0.widening_mul_add_us(e1, e1, e0), add(e1.widening_mul_su(e0)));
+}
+
+fn test_wrapping_mul(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let (ru, _) = u0.overflowing_mul(u1);
     let re = e0.wrapping_mul(e1);
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_wrapping_rem_u(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_wrapping_rem_u(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let ru = if u1.is_zero() { u0 } else { u0.checked_rem(u1).unwrap() };
     let re = e0.wrapping_rem_u(e1);
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_wrapping_shl(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
-    let ru = u0 << (u1.low_u32() as u8);
-    let re = e0 << (e1.u32() % 256);
+fn test_wrapping_shl(e0: E256, _e1: E256, n: u8) {
+    let u0 = U256::from(e0);
+    let ru = u0 << n;
+    let re = e0 << n as u32;
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_wrapping_shr(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
-    let ru = u0 >> (u1.low_u32() as u8);
-    let re = e0 >> (e1.u32() % 256);
+fn test_wrapping_shr(e0: E256, _e1: E256, n: u8) {
+    let u0 = U256::from(e0);
+    let ru = u0 >> n;
+    let re = e0 >> n as u32;
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_wrapping_sub(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_wrapping_sub(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let (ru, _) = u0.overflowing_sub(u1);
     let re = e0.wrapping_sub(e1);
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fn test_xor(data: &[u8]) {
-    let u0 = U256::from_little_endian(&data[0x00..0x20]);
-    let u1 = U256::from_little_endian(&data[0x20..0x40]);
-    let e0 = E256::get(&data[0x00..0x20]);
-    let e1 = E256::get(&data[0x20..0x40]);
+fn test_xor(e0: E256, e1: E256) {
+    let u0 = U256::from(e0);
+    let u1 = U256::from(e1);
     let ru = u0 ^ u1;
     let re = e0 ^ e1;
     assert_eq!(Into::<E256>::into(ru), re);
 }
 
-fuzz_target!(|data: [u8; 64]| {
-    test_and(&data);
-    test_bit(&data);
-    test_bit_clr(&data);
-    test_bit_set(&data);
-    test_clz(&data);
-    test_cmp_u(&data);
-    test_ctz(&data);
-    test_not(&data);
-    test_or(&data);
-    test_saturating_add(&data);
-    test_saturating_sub(&data);
-    test_wrapping_add(&data);
-    test_wrapping_div_u(&data);
-    test_wrapping_mul(&data);
-    test_wrapping_rem_u(&data);
-    test_wrapping_shl(&data);
-    test_wrapping_shr(&data);
-    test_wrapping_sub(&data);
-    test_xor(&data);
+fuzz_target!(|input: (E256, E256, u8)| {
+    let (e0, e1, n) = input;
+    test_and(e0, e1);
+    test_bit(e0, e1, n);
+    test_bit_clr(e0, e1, n);
+    test_bit_set(e0, e1, n);
+    test_borrowing_sub(e0, e1, n);
+    test_brev8(e0, e1);
+    test_carrying_add(e0, e1, n);
+    test_clmul(e0, e1);
+    test_clz(e0, e1);
+    test_cmp_u(e0, e1);
+    test_cpop_under(e0, e1);
+    test_ct(e0, e1);
+    test_ctz(e0, e1);
+    test_extract_bits(e0, e1);
+    test_first_set(e0, e1);
+    test_hamming_distance(e0, e1);
+    test_highest_set_bit(e0, e1);
+    test_insert_bits(e0, e1);
+    test_iota(e0, e1);
+    test_iter_ones(e0, e1);
+    test_lanes(e0, e1, n);
+    test_leading_ones(e0, e1);
+    test_leading_sign_bits(e0, e1);
+    test_lowest_set_bit(e0, e1);
+    test_mul_add(e0, e1);
+    test_not(e0, e1);
+    test_or(e0, e1);
+    test_parity(e0, e1);
+    test_pdep(e0, e1);
+    test_pext(e0, e1);
+    test_reverse_bits(e0, e1);
+    test_rotate_left(e0, e1);
+    test_rotate_right(e0, e1);
+    test_saturating_add(e0, e1);
+    test_saturating_sub(e0, e1);
+    test_set_first(e0, e1);
+    test_swap_bytes(e0, e1);
+    test_trailing_ones(e0, e1);
+    test_widen(e0, e1);
+    test_widening_mul_add(e0, e1);
+    test_wrapping_add(e0, e1);
+    test_wrapping_div_u(e0, e1);
+    test_wrapping_mul(e0, e1);
+    test_wrapping_rem_u(e0, e1);
+    test_wrapping_shl(e0, e1, n);
+    test_wrapping_shr(e0, e1, n);
+    test_wrapping_sub(e0, e1);
+    test_xor(e0, e1);
 });
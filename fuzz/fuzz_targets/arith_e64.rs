@@ -46,6 +46,44 @@ fn test_bit_set(x: u64, y: u64) {
     assert_eq!(r0, r1);
 }
 
+fn test_borrowing_sub(x: u64, y: u64) {
+    let borrow = (x ^ y) & 1 != 0;
+    let r0 = E64(x).borrowing_sub(E64(y), borrow);
+    let r1 = (x as i128) - (y as i128) - (borrow as i128);
+    assert_eq!(r0, (E64(r1 as u64), r1 < 0));
+}
+
+fn test_brev8(x: u64, _y: u64) {
+    let r0 = E64::from(x).brev8();
+    let r1 = E64(u64::from_le_bytes(x.to_le_bytes().map(|e| e.reverse_bits())));
+    assert_eq!(r0, r1);
+}
+
+fn test_carrying_add(x: u64, y: u64) {
+    let carry = (x ^ y) & 1 != 0;
+    let r0 = E64(x).carrying_add(E64(y), carry);
+    let r1 = (x as u128) + (y as u128) + (carry as u128);
+    assert_eq!(r0, (E64(r1 as u64), r1 >> 64 != 0));
+}
+
+fn test_clmul(x: u64, y: u64) {
+    let mut r1 = 0u128;
+    for i in 0..64 {
+        if y >> i & 1 != 0 {
+            r1 ^= (x as u128) << i;
+        }
+    }
+    let r0 = Eint::widening_clmul(E64::from(x), E64::from(y));
+    assert_eq!(r0.0, E64(r1 as u64));
+    assert_eq!(r0.1, E64((r1 >> 64) as u64));
+    assert_eq!(E64::from(x).clmul(E64::from(y)), E64(r1 as u64));
+    assert_eq!(E64::from(x).clmulh(E64::from(y)), E64((r1 >> 64) as u64));
+    let r0 = Eint::widening_clmul(E32::from(x as u32), E32::from(y as u32));
+    let r1 = E64::from(x as u32).clmul(E64::from(y as u32));
+    assert_eq!(r0.0, E32(r1.0 as u32));
+    assert_eq!(r0.1, E32((r1.0 >> 32) as u32));
+}
+
 fn test_clz(x: u64, _y: u64) {
     let r0 = E64::from(x).clz();
     let r1 = x.leading_zeros();
@@ -70,12 +108,103 @@ fn test_cpop(x: u64, _y: u64) {
     assert_eq!(r0, r1);
 }
 
+fn test_cpop_under(x: u64, y: u64) {
+    let r0 = E64::from(x).cpop_under(&E64::from(y));
+    let r1 = (x & y).count_ones();
+    assert_eq!(r0, r1);
+}
+
+fn test_ct(x: u64, y: u64) {
+    let (e0, e1) = (E64::from(x), E64::from(y));
+    assert_eq!(e0.ct_eq(&e1), (x == y) as u8);
+    assert_eq!(e0.ct_eq(&e0), 1);
+    assert_eq!(e0.ct_lt_s(&e1), ((x as i64) < (y as i64)) as u8);
+    assert_eq!(e0.ct_lt_u(&e1), (x < y) as u8);
+    assert_eq!(E64::ct_select(1, e0, e1), e0);
+    assert_eq!(E64::ct_select(0, e0, e1), e1);
+    let (mut a, mut b) = (e0, e1);
+    a.ct_swap(&mut b, 0);
+    assert_eq!((a, b), (e0, e1));
+    a.ct_swap(&mut b, 1);
+    assert_eq!((a, b), (e1, e0));
+    assert_eq!(e0.ct_add(e1), E64(x.wrapping_add(y)));
+    assert_eq!(e0.ct_sub(e1), E64(x.wrapping_sub(y)));
+    assert_eq!(e0.ct_mul(e1), E64(x.wrapping_mul(y)));
+}
+
 fn test_ctz(x: u64, _y: u64) {
     let r0 = E64::from(x).ctz();
     let r1 = x.trailing_zeros();
     assert_eq!(r0, r1);
 }
 
+fn test_extract_bits(x: u64, y: u64) {
+    let start = y as u32 % 72;
+    let len = (y >> 32) as u32 % 72;
+    let r0 = E64::from(x).extract_bits(start, len);
+    let mut r1 = 0u64;
+    for i in 0..len.min(64) {
+        if start + i < 64 && x >> (start + i) & 1 != 0 {
+            r1 |= 1 << i;
+        }
+    }
+    assert_eq!(r0, E64(r1));
+}
+
+fn test_first_set(x: u64, _y: u64) {
+    let r0 = E64::from(x).first_set();
+    let r1 = (0..64).find(|&i| x >> i & 1 != 0);
+    assert_eq!(r0, r1);
+}
+
+fn test_get_partial(x: u64, y: u64) {
+    let n = (y % 9) as usize;
+    let s = 64 - 8 * n as u32;
+    let z = if n == 0 { 0 } else { x << s >> s };
+    let i = if n == 0 { 0 } else { ((x << s) as i64 >> s) as u64 };
+    assert_eq!(E64::get_partial(&x.to_le_bytes(), n, Extension::Zero), E64::from(z));
+    assert_eq!(E64::get_partial(&x.to_le_bytes(), n, Extension::Sign), E64::from(i));
+    let mut mem = y.to_le_bytes();
+    E64::from(x).put_partial(&mut mem, n);
+    let m = if n == 0 { 0 } else { u64::MAX >> s };
+    assert_eq!(u64::from_le_bytes(mem), x & m | y & !m);
+    let mut mem = y.to_le_bytes();
+    E64::from(x).put_masked(&mut mem, E64::from(x >> 56));
+    let m = (0..8).filter(|i| x >> (56 + i) & 1 != 0).fold(0u64, |m, i| m | 0xff << (i * 8));
+    assert_eq!(u64::from_le_bytes(mem), x & m | y & !m);
+}
+
+fn test_hamming_distance(x: u64, y: u64) {
+    let r0 = E64::from(x).hamming_distance(&E64::from(y));
+    let r1 = (x ^ y).count_ones();
+    assert_eq!(r0, r1);
+}
+
+fn test_highest_set_bit(x: u64, _y: u64) {
+    let r0 = E64::from(x).highest_set_bit();
+    let r1 = x.checked_ilog2();
+    assert_eq!(r0, r1);
+}
+
+fn test_insert_bits(x: u64, y: u64) {
+    let start = y as u32 % 72;
+    let len = (y >> 32) as u32 % 72;
+    let r0 = E64::from(x).insert_bits(start, len, E64::from(!y));
+    let mut r1 = x;
+    for i in 0..len.min(64) {
+        if start + i < 64 {
+            r1 = r1 & !(1 << (start + i)) | ((!y >> i) & 1) << (start + i);
+        }
+    }
+    assert_eq!(r0, E64(r1));
+}
+
+fn test_iota(x: u64, _y: u64) {
+    let r0: Vec<u32> = E64::from(x).iota().collect();
+    let r1: Vec<u32> = (0..64).map(|i| (x & ((1u128 << i) - 1) as u64).count_ones()).collect();
+    assert_eq!(r0, r1);
+}
+
 fn test_is_negative(x: u64, _y: u64) {
     let r0 = E64::from(x).is_negative();
     let r1 = (x as i64) < 0;
@@ -91,45 +220,171 @@ fn test_is_positive(x: u64, _y: u64) {
 fn test_overflowing_add_s(x: u64, y: u64) {
     let (r0, b0) = Eint::overflowing_add_s(E64::from(x), E64::from(y));
     let (r1, b1) = (x as i64).overflowing_add(y as i64);
-    assert_eq!(r0, r1.into());
+    assert_eq!(r0, E64::from(r1));
     assert_eq!(b0, b1);
 }
 
 fn test_overflowing_add_u(x: u64, y: u64) {
     let (r0, b0) = Eint::overflowing_add_u(E64::from(x), E64::from(y));
     let (r1, b1) = x.overflowing_add(y);
-    assert_eq!(r0, r1.into());
+    assert_eq!(r0, E64::from(r1));
     assert_eq!(b0, b1);
 }
 
 fn test_overflowing_mul_s(x: u64, y: u64) {
     let (r0, b0) = Eint::overflowing_mul_s(E64::from(x), E64::from(y));
     let (r1, b1) = (x as i64).overflowing_mul(y as i64);
-    assert_eq!(r0, r1.into());
+    assert_eq!(r0, E64::from(r1));
     assert_eq!(b0, b1);
 }
 
 fn test_overflowing_mul_u(x: u64, y: u64) {
     let (r0, b0) = Eint::overflowing_mul_u(E64::from(x), E64::from(y));
     let (r1, b1) = x.overflowing_mul(y);
-    assert_eq!(r0, r1.into());
+    assert_eq!(r0, E64::from(r1));
     assert_eq!(b0, b1);
 }
 
 fn test_overflowing_sub_s(x: u64, y: u64) {
     let (r0, b0) = Eint::overflowing_sub_s(E64::from(x), E64::from(y));
     let (r1, b1) = (x as i64).overflowing_sub(y as i64);
-    assert_eq!(r0, r1.into());
+    assert_eq!(r0, E64::from(r1));
     assert_eq!(b0, b1);
 }
 
 fn test_overflowing_sub_u(x: u64, y: u64) {
     let (r0, b0) = Eint::overflowing_sub_u(E64::from(x), E64::from(y));
     let (r1, b1) = x.overflowing_sub(y);
-    assert_eq!(r0, r1.into());
+    assert_eq!(r0, E64::from(r1));
     assert_eq!(b0, b1);
 }
 
+fn test_iter_ones(x: u64, _y: u64) {
+    let r0: Vec<u32> = E64::from(x).iter_ones().collect();
+    let r1: Vec<u32> = (0..64).filter(|&i| x >> i & 1 != 0).collect();
+    assert_eq!(r0, r1);
+}
+
+fn test_lanes(x: u64, y: u64) {
+    fn map<const N: usize, T: Copy>(x: [T; N], y: [T; N], f: impl Fn(T, T) -> T) -> [T; N] {
+        core::array::from_fn(|i| f(x[i], y[i]))
+    }
+    let (e0, e1) = (E64::from(x), E64::from(y));
+    let (b0, b1) = (x.to_le_bytes(), y.to_le_bytes());
+    let b = |f: fn(u8, u8) -> u8| E64::from(u64::from_le_bytes(map(b0, b1, f)));
+    assert_eq!(e0.lanes_add::<E8>(e1), b(u8::wrapping_add));
+    assert_eq!(e0.lanes_sub::<u8>(e1), b(u8::wrapping_sub));
+    assert_eq!(e0.lanes_max_u::<E8>(e1), b(u8::max));
+    assert_eq!(e0.lanes_min_u::<E8>(e1), b(u8::min));
+    assert_eq!(e0.lanes_max_s::<E8>(e1), b(|p, q| (p as i8).max(q as i8) as u8));
+    assert_eq!(e0.lanes_min_s::<E8>(e1), b(|p, q| (p as i8).min(q as i8) as u8));
+    let n = y as u32;
+    assert_eq!(e0.lanes_shl::<E8>(n), E64::from(u64::from_le_bytes(b0.map(|p| p.wrapping_shl(n)))));
+    let m = (0..8).filter(|&i| b0[i] == b1[i]).fold(0u64, |m, i| m | 1 << i);
+    assert_eq!(e0.lanes_cmp_eq::<E8>(e1), E64::from(m));
+    let (w0, w1) = ([x as u32, (x >> 32) as u32], [y as u32, (y >> 32) as u32]);
+    let w = |r: [u32; 2]| E64::from(r[0] as u64 | (r[1] as u64) << 32);
+    assert_eq!(e0.lanes_add::<E32>(e1), w(map(w0, w1, u32::wrapping_add)));
+    assert_eq!(e0.lanes_sub::<E32>(e1), w(map(w0, w1, u32::wrapping_sub)));
+    assert_eq!(e0.lanes_max_s::<E32>(e1), w(map(w0, w1, |p, q| (p as i32).max(q as i32) as u32)));
+    assert_eq!(e0.lanes_min_u::<E32>(e1), w(map(w0, w1, u32::min)));
+    assert_eq!(e0.lanes_shl::<E32>(n), w(w0.map(|p| p.wrapping_shl(n))));
+    assert_eq!(e0.lanes_max_u::<E64>(e1), E64::from(x.max(y)));
+    assert_eq!(e0.lanes_min_s::<E64>(e1), E64::from((x as i64).min(y as i64) as u64));
+    assert_eq!(e0.lanes_cmp_eq::<E64>(e1), E64::from(x == y));
+}
+
+fn test_leading_ones(x: u64, _y: u64) {
+    let r0 = E64::from(x).leading_ones();
+    let r1 = x.leading_ones();
+    assert_eq!(r0, r1);
+}
+
+fn test_leading_sign_bits(x: u64, _y: u64) {
+    let r0 = E64::from(x).leading_sign_bits();
+    let r1 = (x ^ ((x as i64) >> 1) as u64).leading_zeros() - 1;
+    assert_eq!(r0, r1);
+}
+
+fn test_lowest_set_bit(x: u64, _y: u64) {
+    let r0 = E64::from(x).lowest_set_bit();
+    let r1 = if x == 0 { None } else { Some(x.trailing_zeros()) };
+    assert_eq!(r0, r1);
+}
+
+fn test_mask(_x: u64, y: u64) {
+    let n = y as u32 % 72;
+    let r0 = E64::mask(n);
+    let r1 = E64(if n >= 64 { u64::MAX } else { (1 << n) - 1 });
+    assert_eq!(r0, r1);
+}
+
+fn test_mul_add(x: u64, y: u64) {
+    assert_eq!(E64(x).mul_add(E64(y), E64(x ^ y)), E64(x.wrapping_mul(y).wrapping_add(x ^ y)));
+    assert_eq!(E64(x).mul_sub_neg(E64(y), E64(x ^ y)), E64((x ^ y).wrapping_sub(x.wrapping_mul(y))));
+}
+
+fn test_ops_prim(x: u64, y: u64) {
+    assert_eq!(E64(x) + y, E64(x.wrapping_add(y)));
+    assert_eq!(E64(x) - (y as i32), E64(x.wrapping_sub(y as i32 as i64 as u64)));
+    assert_eq!(E64(x) * (y as u8), E64(x.wrapping_mul(y as u8 as u64)));
+    assert_eq!(E64(x) == y, x == y);
+    assert_eq!(E64(x).partial_cmp(&(y as u128)), x.partial_cmp(&y));
+    assert_eq!(E64(x) << E8(y as u8), E64(x.wrapping_shl(y as u32)));
+    assert_eq!(E64(x) >> E256::from(y), E64(x.wrapping_shr(y as u32)));
+}
+
+fn test_parity(x: u64, _y: u64) {
+    let r0 = E64::from(x).parity();
+    let r1 = x.count_ones() % 2 == 1;
+    assert_eq!(r0, r1);
+}
+
+fn test_pdep(x: u64, y: u64) {
+    let r0 = E64::from(x).pdep(E64::from(y));
+    let mut r1 = 0u64;
+    let mut k = 0;
+    for i in 0..64 {
+        if y >> i & 1 != 0 {
+            r1 |= (x >> k & 1) << i;
+            k += 1;
+        }
+    }
+    assert_eq!(r0, E64(r1));
+}
+
+fn test_pext(x: u64, y: u64) {
+    let r0 = E64::from(x).pext(E64::from(y));
+    let mut r1 = 0u64;
+    let mut k = 0;
+    for i in 0..64 {
+        if y >> i & 1 != 0 {
+            r1 |= (x >> i & 1) << k;
+            k += 1;
+        }
+    }
+    assert_eq!(r0, E64(r1));
+    assert_eq!(r0.pdep(E64::from(y)), E64(x & y));
+}
+
+fn test_reverse_bits(x: u64, _y: u64) {
+    let r0 = E64::from(x).reverse_bits();
+    let r1 = E64(x.reverse_bits());
+    assert_eq!(r0, r1);
+}
+
+fn test_rotate_left(x: u64, y: u32) {
+    let r0 = E64::from(x).rotate_left(y);
+    let r1 = E64(x.rotate_left(y));
+    assert_eq!(r0, r1);
+}
+
+fn test_rotate_right(x: u64, y: u32) {
+    let r0 = E64::from(x).rotate_right(y);
+    let r1 = E64(x.rotate_right(y));
+    assert_eq!(r0, r1);
+}
+
 fn test_saturating_add_s(x: u64, y: u64) {
     let (r0, b0) = Eint::saturating_add_s(E64::from(x), E64::from(y));
     let r1 = E64((x as i64).saturating_add(y as i64) as u64);
@@ -162,6 +417,57 @@ fn test_saturating_sub_u(x: u64, y: u64) {
     assert_eq!(b0, b1);
 }
 
+fn test_set_first(x: u64, _y: u64) {
+    let i = (0..64).find(|&i| x >> i & 1 != 0);
+    let sbf = match i {
+        Some(i) => (1u64 << i) - 1,
+        None => u64::MAX,
+    };
+    let sif = match i {
+        Some(i) => u64::MAX >> (63 - i),
+        None => u64::MAX,
+    };
+    let sof = match i {
+        Some(i) => 1u64 << i,
+        None => 0,
+    };
+    assert_eq!(E64::from(x).set_before_first(), E64(sbf));
+    assert_eq!(E64::from(x).set_including_first(), E64(sif));
+    assert_eq!(E64::from(x).set_only_first(), E64(sof));
+}
+
+fn test_swap_bytes(x: u64, _y: u64) {
+    let r0 = E64::from(x).swap_bytes();
+    let r1 = E64(x.swap_bytes());
+    assert_eq!(r0, r1);
+}
+
+fn test_trailing_ones(x: u64, _y: u64) {
+    let r0 = E64::from(x).trailing_ones();
+    let r1 = x.trailing_ones();
+    assert_eq!(r0, r1);
+}
+
+fn test_widen(x: u64, y: u64) {
+    let (sx, sy) = (x as i64 as i128 as u128, y as i64 as i128 as u128);
+    let wide = E64::concat(E64(x), E64(y));
+    assert_eq!(wide, E128((y as u128) << 64 | x as u128));
+    assert_eq!(wide.split(), (E64(x), E64(y)));
+    assert_eq!(E64(x).widen_s(), E128(sx));
+    assert_eq!(E64(x).widen_u(), E128(x as u128));
+    assert_eq!(E64(x).add_wide_s(E64(y)), E128(sx.wrapping_add(sy)));
+    assert_eq!(E64(x).add_wide_u(E64(y)), E128(x as u128 + y as u128));
+    assert_eq!(E64(x).mul_wide_s(E64(y)), E128(sx.wrapping_mul(sy)));
+    assert_eq!(E64(x).mul_wide_su(E64(y)), E128(sx.wrapping_mul(y as u128)));
+    assert_eq!(E64(x).mul_wide_u(E64(y)), E128(x as u128 * y as u128));
+    assert_eq!(E64(x).sub_wide_s(E64(y)), E128(sx.wrapping_sub(sy)));
+    assert_eq!(E64(x).sub_wide_u(E64(y)), E128((x as u128).wrapping_sub(y as u128)));
+    assert_eq!(E64::add_wv_s(wide, E64(y)), E128(wide.0.wrapping_add(sy)));
+    assert_eq!(E64::add_wv_u(wide, E64(y)), E128(wide.0.wrapping_add(y as u128)));
+    assert_eq!(E64::sub_wv_s(wide, E64(y)), E128(wide.0.wrapping_sub(sy)));
+    assert_eq!(E64::sub_wv_u(wide, E64(y)), E128(wide.0.wrapping_sub(y as u128)));
+}
+
 fn test_widening_add_s(x: u64, y: u64) {
     let r0 = Eint::widening_add_s(E64::from(x), E64::from(y));
     let r1 = x as i64 as i128 + y as i64 as i128;
@@ -176,6 +482,23 @@ fn test_widening_add_u(x: u64, y: u64) {
     assert_eq!(r0.1, E64((r1 >> 64) as u64));
 }
 
+fn test_widening_mul_add(x: u64, y: u64) {
+    let acc = (y as u128) | (x as u128) << 64;
+    let split = |r: u128| (E64(r as u64), E64((r >> 64) as u64));
+    let r0 = E64(x).widening_mul_add_s(E64(y), E64(y), E64(x));
+    let r1 = ((x as i64 as i128).wrapping_mul(y as i64 as i128) as u128).wrapping_add(acc);
+    assert_eq!(r0, split(r1));
+    let r0 = E64(x).widening_mul_add_su(E64(y), E64(y), E64(x));
+    let r1 = ((x as i64 as i128).wrapping_mul(y as i128) as u128).wrapping_add(acc);
+    assert_eq!(r0, split(r1));
+    let r0 = E64(x).widening_mul_add_u(E64(y), E64(y), E64(x));
+    let r1 = (x as u128).wrapping_mul(y as u128).wrapping_add(acc);
+    assert_eq!(r0, split(r1));
+    let r0 = E64(x).widening_mul_add_us(E64(y), E64(y), E64(x));
+    let r1 = ((x as i128).wrapping_mul(y as i64 as i128) as u128).wrapping_add(acc);
+    assert_eq!(r0, split(r1));
+}
+
 fn test_widening_mul_s(x: u64, y: u64) {
     let r0 = Eint::widening_mul_s(E64::from(x), E64::from(y));
     let r1 = (x as i64 as i128 * y as i64 as i128) as u128;
@@ -283,46 +606,79 @@ fn test_wrapping_sub(x: u64, y: u64) {
     assert_eq!(r0, r1);
 }
 
-fuzz_target!(|data: (u64, u64)| {
-    test_average_add_s(data.0, data.1);
-    test_average_add_u(data.0, data.1);
-    test_average_sub_s(data.0, data.1);
-    test_average_sub_u(data.0, data.1);
-    test_bit(data.0, data.1);
-    test_bit_clr(data.0, data.1);
-    test_bit_set(data.0, data.1);
-    test_clz(data.0, data.1);
-    test_cmp_s(data.0, data.1);
-    test_cmp_u(data.0, data.1);
-    test_cpop(data.0, data.1);
-    test_ctz(data.0, data.1);
-    test_is_negative(data.0, data.1);
-    test_is_positive(data.0, data.1);
-    test_overflowing_add_s(data.0, data.1);
-    test_overflowing_add_u(data.0, data.1);
-    test_overflowing_mul_s(data.0, data.1);
-    test_overflowing_mul_u(data.0, data.1);
-    test_overflowing_sub_s(data.0, data.1);
-    test_overflowing_sub_u(data.0, data.1);
-    test_saturating_add_s(data.0, data.1);
-    test_saturating_add_u(data.0, data.1);
-    test_saturating_sub_s(data.0, data.1);
-    test_saturating_sub_u(data.0, data.1);
-    test_widening_add_s(data.0, data.1);
-    test_widening_add_u(data.0, data.1);
-    test_widening_mul_s(data.0, data.1);
-    test_widening_mul_su(data.0, data.1);
-    test_widening_mul_u(data.0, data.1);
-    test_widening_sub_s(data.0, data.1);
-    test_widening_sub_u(data.0, data.1);
-    test_wrapping_add(data.0, data.1);
-    test_wrapping_div_s(data.0, data.1);
-    test_wrapping_div_u(data.0, data.1);
-    test_wrapping_mul(data.0, data.1);
-    test_wrapping_rem_s(data.0, data.1);
-    test_wrapping_rem_u(data.0, data.1);
-    test_wrapping_sub(data.0, data.1);
-    test_wrapping_shl(data.0, data.1 as u32);
-    test_wrapping_shr(data.0, data.1 as u32);
-    test_wrapping_sra(data.0, data.1 as u32);
+fuzz_target!(|input: (E64, E64, u8)| {
+    let (x, y, n) = (input.0 .0, input.1 .0, input.2 as u32);
+    test_average_add_s(x, y);
+    test_average_add_u(x, y);
+    test_average_sub_s(x, y);
+    test_average_sub_u(x, y);
+    test_bit(x, y);
+    test_bit_clr(x, y);
+    test_bit_set(x, y);
+    test_borrowing_sub(x, y);
+    test_brev8(x, y);
+    test_carrying_add(x, y);
+    test_clmul(x, y);
+    test_clz(x, y);
+    test_cmp_s(x, y);
+    test_cmp_u(x, y);
+    test_cpop(x, y);
+    test_cpop_under(x, y);
+    test_ct(x, y);
+    test_ctz(x, y);
+    test_extract_bits(x, y);
+    test_first_set(x, y);
+    test_get_partial(x, y);
+    test_hamming_distance(x, y);
+    test_highest_set_bit(x, y);
+    test_insert_bits(x, y);
+    test_iota(x, y);
+    test_is_negative(x, y);
+    test_is_positive(x, y);
+    test_overflowing_add_s(x, y);
+    test_overflowing_add_u(x, y);
+    test_overflowing_mul_s(x, y);
+    test_overflowing_mul_u(x, y);
+    test_overflowing_sub_s(x, y);
+    test_overflowing_sub_u(x, y);
+    test_iter_ones(x, y);
+    test_lanes(x, y);
+    test_leading_ones(x, y);
+    test_leading_sign_bits(x, y);
+    test_lowest_set_bit(x, y);
+    test_mask(x, y);
+    test_mul_add(x, y);
+    test_ops_prim(x, y);
+    test_parity(x, y);
+    test_pdep(x, y);
+    test_pext(x, y);
+    test_reverse_bits(x, y);
+    test_rotate_left(x, n);
+    test_rotate_right(x, n);
+    test_saturating_add_s(x, y);
+    test_saturating_add_u(x, y);
+    test_saturating_sub_s(x, y);
+    test_saturating_sub_u(x, y);
+    test_set_first(x, y);
+    test_swap_bytes(x, y);
+    test_trailing_ones(x, y);
+    test_widen(x, y);
+    test_widening_add_s(x, y);
+    test_widening_add_u(x, y);
+    test_widening_mul_add(x, y);
+    test_widening_mul_s(x, y);
+    test_widening_mul_su(x, y);
+    test_widening_mul_u(x, y);
+    test_widening_sub_s(x, y);
+    test_widening_sub_u(x, y);
+    test_wrapping_add(x, y);
+    test_wrapping_div_s(x, y);
+    test_wrapping_div_u(x, y);
+    test_wrapping_mul(x, y);
+    test_wrapping_rem_s(x, y);
+    test_wrapping_rem_u(x, y);
+    test_wrapping_sub(x, y);
+    test_wrapping_shl(x, n);
+    test_wrapping_shr(x, n);
+    test_wrapping_sra(x, n);
 });
@@ -0,0 +1,53 @@
+use crate::*;
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Generates an integer biased towards edge cases: 0, 1, MAX_U, MIN_S, MAX_S, single-bit values and values near limb
+/// boundaries. About half of the values are uniformly random.
+fn arbitrary_eint<T: Eint>(u: &mut Unstructured) -> Result<T> {
+    let step = if T::BITS > 64 { 64 } else { T::BITS / 2 };
+    match u.int_in_range(0u8..=15)? {
+        0 => Ok(T::ZERO),
+        1 => Ok(T::ONE),
+        2 => Ok(T::MAX_U),
+        3 => Ok(T::MIN_S),
+        4 => Ok(T::MAX_S),
+        5 => Ok(T::ONE << u.int_in_range(0..=T::BITS - 1)?),
+        6 | 7 => {
+            let k = u.int_in_range(1..=T::BITS / step - 1)?;
+            let r = (T::ONE << (k * step)).wrapping_add(T::from(u.int_in_range(-1i8..=1)?));
+            Ok(if bool::arbitrary(u)? { -r } else { r })
+        }
+        _ => {
+            let mut mem = [0u8; 256];
+            let size = T::BITS as usize >> 3;
+            u.fill_buffer(&mut mem[..size])?;
+            Ok(T::get(&mem[..size]))
+        }
+    }
+}
+
+macro_rules! impl_arbitrary {
+    ($name:ty) => {
+        impl<'a> Arbitrary<'a> for $name {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                arbitrary_eint(u)
+            }
+
+            fn size_hint(_: usize) -> (usize, Option<usize>) {
+                // One byte picks the branch. The near-boundary branch takes up to three more bytes, which is more
+                // than the uniform branch for E8 and E16.
+                (1, Some(1 + (<$name>::BITS as usize >> 3).max(3)))
+            }
+        }
+    };
+}
+
+impl_arbitrary!(E8);
+impl_arbitrary!(E16);
+impl_arbitrary!(E32);
+impl_arbitrary!(E64);
+impl_arbitrary!(E128);
+impl_arbitrary!(E256);
+impl_arbitrary!(E512);
+impl_arbitrary!(E1024);
+impl_arbitrary!(E2048);
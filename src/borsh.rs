@@ -0,0 +1,35 @@
+//! Borsh support. Integers are encoded as fixed-length little-endian byte arrays.
+
+use crate::*;
+use ::borsh::io::{Read, Result, Write};
+use ::borsh::{BorshDeserialize, BorshSerialize};
+
+macro_rules! impl_borsh {
+    ($name:ty) => {
+        impl BorshSerialize for $name {
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                let mut mem = [0u8; <$name>::BITS as usize >> 3];
+                self.put(&mut mem);
+                writer.write_all(&mem)
+            }
+        }
+
+        impl BorshDeserialize for $name {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                let mut mem = [0u8; <$name>::BITS as usize >> 3];
+                reader.read_exact(&mut mem)?;
+                Ok(Self::get(&mem))
+            }
+        }
+    };
+}
+
+impl_borsh!(E8);
+impl_borsh!(E16);
+impl_borsh!(E32);
+impl_borsh!(E64);
+impl_borsh!(E128);
+impl_borsh!(E256);
+impl_borsh!(E512);
+impl_borsh!(E1024);
+impl_borsh!(E2048);
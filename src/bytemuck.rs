@@ -0,0 +1,19 @@
+use crate::*;
+
+macro_rules! impl_bytemuck {
+    ($name:ty) => {
+        // SAFETY: the type is repr(transparent) over a primitive integer or an array of u64, both of which are Pod.
+        unsafe impl ::bytemuck::Pod for $name {}
+        unsafe impl ::bytemuck::Zeroable for $name {}
+    };
+}
+
+impl_bytemuck!(E8);
+impl_bytemuck!(E16);
+impl_bytemuck!(E32);
+impl_bytemuck!(E64);
+impl_bytemuck!(E128);
+impl_bytemuck!(E256);
+impl_bytemuck!(E512);
+impl_bytemuck!(E1024);
+impl_bytemuck!(E2048);
@@ -0,0 +1,62 @@
+use crate::*;
+
+impl From<E256> for ::ethnum::U256 {
+    fn from(e: E256) -> Self {
+        let lo = e.0[0] as u128 | (e.0[1] as u128) << 64;
+        let hi = e.0[2] as u128 | (e.0[3] as u128) << 64;
+        Self::from_words(hi, lo)
+    }
+}
+
+impl From<::ethnum::U256> for E256 {
+    fn from(u: ::ethnum::U256) -> Self {
+        let (hi, lo) = u.into_words();
+        Self([lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64])
+    }
+}
+
+/// Reinterprets the bits as a two's complement signed integer.
+impl From<E256> for ::ethnum::I256 {
+    fn from(e: E256) -> Self {
+        ::ethnum::U256::from(e).as_i256()
+    }
+}
+
+/// Reinterprets the bits of a two's complement signed integer.
+impl From<::ethnum::I256> for E256 {
+    fn from(i: ::ethnum::I256) -> Self {
+        Self::from(i.as_u256())
+    }
+}
+
+macro_rules! impl_ethnum_try_from {
+    ($name:ident) => {
+        impl TryFrom<$name> for ::ethnum::U256 {
+            type Error = TryFromEintError;
+
+            fn try_from(e: $name) -> Result<Self, Self::Error> {
+                if e.0[4..].iter().any(|&l| l != 0) {
+                    return Err(TryFromEintError(()));
+                }
+                Ok(Self::from(E256([e.0[0], e.0[1], e.0[2], e.0[3]])))
+            }
+        }
+
+        /// Succeeds if the value, read as a two's complement signed integer, fits in 256 bits.
+        impl TryFrom<$name> for ::ethnum::I256 {
+            type Error = TryFromEintError;
+
+            fn try_from(e: $name) -> Result<Self, Self::Error> {
+                let s = if e.0[3] >> 63 != 0 { u64::MAX } else { 0 };
+                if e.0[4..].iter().any(|&l| l != s) {
+                    return Err(TryFromEintError(()));
+                }
+                Ok(Self::from(E256([e.0[0], e.0[1], e.0[2], e.0[3]])))
+            }
+        }
+    };
+}
+
+impl_ethnum_try_from!(E512);
+impl_ethnum_try_from!(E1024);
+impl_ethnum_try_from!(E2048);
@@ -0,0 +1,865 @@
+//! The layered traits behind [`Eint`](crate::Eint). Each trait is a direct extension of [`EintBase`], so generic code
+//! can bound on just the operations it uses. All of them are sealed and implemented for E8 to E2048.
+//!
+//! [`Eint`](crate::Eint) offers every item of these traits under the same name. Bring either `Eint` or the traits of
+//! this module into scope, as calling a method on a concrete type with both in scope is ambiguous.
+
+use crate::{private, Extension, Iota, IterOnes, Lane, Lanes, LimbBuf, LimbsMut};
+
+/// Returns true if the highest bit of x is set.
+fn msb<T: EintBase>(x: T) -> bool {
+    x > T::MAX_S
+}
+
+/// Calculates x + y + carry, returning the sum and the output carry.
+fn adc<T: EintBase>(x: T, y: T, carry: bool) -> (T, bool) {
+    let r = x + y + T::from(carry);
+    (r, r < x || carry && r == x)
+}
+
+/// Calculates x - y - borrow, returning the difference and the output borrow.
+fn sbb<T: EintBase>(x: T, y: T, borrow: bool) -> (T, bool) {
+    (x - y - T::from(borrow), x < y || borrow && x == y)
+}
+
+/// Applies f to each pair of u64 limbs of x and y. The third argument of f has the highest bit of each lane of type L
+/// set. Panics if L is wider than T.
+fn map_lanes<T: EintBase, L: Lane>(x: T, y: T, f: impl Fn(u64, u64, u64) -> u64) -> T {
+    assert!(L::WIDTH <= T::BITS, "lane wider than integer");
+    let h = (u64::MAX / (u64::MAX >> (64 - L::WIDTH))) << (L::WIDTH - 1);
+    let mut r = T::ZERO;
+    for i in 0..T::LIMBS {
+        r.set_limb(i, f(x.limb(i), y.limb(i), h));
+    }
+    r
+}
+
+/// Lane-wise wrapping addition, where h has the highest bit of each lane set.
+fn swar_add(x: u64, y: u64, h: u64) -> u64 {
+    ((x & !h) + (y & !h)) ^ ((x ^ y) & h)
+}
+
+/// Lane-wise wrapping subtraction, where h has the highest bit of each lane set.
+fn swar_sub(x: u64, y: u64, h: u64) -> u64 {
+    ((x | h) - (y & !h)) ^ ((x ^ !y) & h)
+}
+
+/// Returns a mask with all bits of a lane set if the lane of x is less than the lane of y as unsigned integers, where h
+/// has the highest bit of each lane set.
+fn swar_lt_u(x: u64, y: u64, h: u64) -> u64 {
+    let b = (!x & y | !(x ^ y) & swar_sub(x, y, h)) & h;
+    b | (b - (b >> h.trailing_zeros()))
+}
+
+/// Constants, conversions and memory access shared by all integer types. This trait is sealed and cannot be
+/// implemented outside of this crate.
+pub trait EintBase:
+    Clone
+    + Copy
+    + Default
+    + Eq
+    + From<bool>
+    + From<i8>
+    + From<i16>
+    + From<i32>
+    + From<i64>
+    + From<i128>
+    + From<u8>
+    + From<u16>
+    + From<u32>
+    + From<u64>
+    + From<u128>
+    + From<Self>
+    + PartialEq
+    + core::cmp::Ord
+    + core::cmp::PartialOrd
+    + core::fmt::Debug
+    + core::fmt::Display
+    + core::fmt::LowerHex
+    + core::ops::Add<Output = Self>
+    + core::ops::AddAssign
+    + core::ops::BitAnd<Output = Self>
+    + core::ops::BitAndAssign
+    + core::ops::BitOr<Output = Self>
+    + core::ops::BitOrAssign
+    + core::ops::BitXor<Output = Self>
+    + core::ops::BitXorAssign
+    + core::ops::Div<Output = Self>
+    + core::ops::DivAssign
+    + core::ops::Mul<Output = Self>
+    + core::ops::MulAssign
+    + core::ops::Neg<Output = Self>
+    + core::ops::Not<Output = Self>
+    + core::ops::Rem<Output = Self>
+    + core::ops::RemAssign
+    + core::ops::Sub<Output = Self>
+    + core::ops::SubAssign
+    + core::ops::Shl<u32, Output = Self>
+    + core::ops::Shr<u32, Output = Self>
+    + private::Sealed
+{
+    const BITS: u32;
+
+    /// The number of u64 limbs needed to hold the integer. E8 to E64 use a single limb.
+    const LIMBS: usize = (Self::BITS as usize + 63) >> 6;
+
+    const MAX_S: Self;
+
+    const MAX_U: Self;
+
+    const MIN_S: Self;
+
+    const MIN_U: Self;
+
+    const ONE: Self;
+
+    const ZERO: Self;
+
+    /// Creates an integer from its u64 limbs, least significant limb first. Missing limbs are taken as zero, limbs and
+    /// bits beyond the width of the integer are ignored.
+    fn from_limbs(limbs: &[u64]) -> Self {
+        let mut r = Self::ZERO;
+        for (i, e) in limbs.iter().take(Self::LIMBS).enumerate() {
+            r.set_limb(i, *e);
+        }
+        r
+    }
+
+    /// Get a native endian integer value from its representation as a byte slice in little endian.
+    fn get(mem: &[u8]) -> Self {
+        unsafe { core::ptr::read(mem.as_ptr() as *const _) }
+    }
+
+    /// Loads the lower nbytes bytes of the integer from memory in little-endian byte order and fills the upper bytes
+    /// according to ext. Sign extension copies the highest bit of the last loaded byte, and loading zero bytes gives
+    /// zero. nbytes ranges from 0 to Self::BITS / 8, which is 1 for E8, 2 for E16 and so on up to 256 for E2048.
+    /// Panics if nbytes is out of this range or mem is shorter than nbytes.
+    fn get_partial(mem: &[u8], nbytes: usize, ext: Extension) -> Self {
+        let n = Self::BITS as usize >> 3;
+        assert!(nbytes <= n, "partial width out of range");
+        let mut buf = [0u8; 256];
+        buf[..nbytes].copy_from_slice(&mem[..nbytes]);
+        if ext == Extension::Sign && nbytes != 0 && buf[nbytes - 1] & 0x80 != 0 {
+            buf[nbytes..n].fill(0xff);
+        }
+        Self::get(&buf[..n])
+    }
+
+    /// Returns the i-th u64 limb, where limb 0 holds the least significant bits. Panics if i is not less than
+    /// Self::LIMBS.
+    fn limb(&self, i: usize) -> u64;
+
+    /// Returns a copy of the u64 limbs, least significant limb first. Works for every width, including E8 to E128
+    /// whose value is not stored as a u64 array; the result dereferences to a slice of Self::LIMBS limbs.
+    fn limbs(&self) -> LimbBuf {
+        LimbBuf { limbs: core::array::from_fn(|i| if i < Self::LIMBS { self.limb(i) } else { 0 }), len: Self::LIMBS }
+    }
+
+    /// Returns a mutable view of the u64 limbs, least significant limb first. The limbs are written back when the view
+    /// is dropped, ignoring bits beyond the width of the integer as [`EintBase::set_limb`] does.
+    fn limbs_mut(&mut self) -> LimbsMut<'_, Self> {
+        let buf = self.limbs();
+        LimbsMut { data: self, buf, store: |x, l| *x = Self::from_limbs(l) }
+    }
+
+    /// Save the integer as a byte array in little-endian byte order to memory.
+    fn put(&self, mem: &mut [u8]);
+
+    /// Put the lower part integer as a byte array in little-endian byte order to memory. The lower half of E8 is a
+    /// nibble, which is written as a single byte with the upper nibble cleared.
+    fn put_lo(&self, mem: &mut [u8]);
+
+    /// Stores the bytes of the integer whose bit is set in mask to memory, where bit i of mask selects byte i in
+    /// little-endian byte order. The other bytes of memory are left unchanged. Only the lower Self::BITS / 8 bits of
+    /// mask select bytes, the bits above them are ignored. Panics if mem is shorter than Self::BITS / 8 bytes, i.e. 1
+    /// for E8 up to 256 for E2048.
+    fn put_masked(&self, mem: &mut [u8], mask: Self) {
+        let n = Self::BITS as usize >> 3;
+        assert!(mem.len() >= n, "memory too short");
+        let mut buf = [0u8; 256];
+        self.put(&mut buf[..n]);
+        for i in 0..n {
+            if mask.limb(i >> 6) >> (i & 63) & 1 != 0 {
+                mem[i] = buf[i];
+            }
+        }
+    }
+
+    /// Stores the lower nbytes bytes of the integer to memory in little-endian byte order, leaving the rest of memory
+    /// unchanged. The integer is truncated to nbytes bytes, so the upper bytes are silently dropped. nbytes ranges from
+    /// 0 to Self::BITS / 8, which is 1 for E8, 2 for E16 and so on up to 256 for E2048. Panics if nbytes is out of
+    /// this range or mem is shorter than nbytes.
+    fn put_partial(&self, mem: &mut [u8], nbytes: usize) {
+        let n = Self::BITS as usize >> 3;
+        assert!(nbytes <= n, "partial width out of range");
+        let mut buf = [0u8; 256];
+        self.put(&mut buf[..n]);
+        mem[..nbytes].copy_from_slice(&buf[..nbytes]);
+    }
+
+    /// Sets the i-th u64 limb. Bits beyond the width of the integer are ignored. Panics if i is not less than
+    /// Self::LIMBS.
+    fn set_limb(&mut self, i: usize, v: u64);
+
+    /// Converts to an arbitrary-precision integer, interpreting self as a two's complement signed integer.
+    #[cfg(feature = "num-bigint")]
+    fn to_bigint_s(&self) -> ::num_bigint::BigInt {
+        let mut mem = [0u8; 256];
+        let n = Self::BITS as usize >> 3;
+        self.put(&mut mem[..n]);
+        ::num_bigint::BigInt::from_signed_bytes_le(&mem[..n])
+    }
+
+    /// Returns the lower 8 bits.
+    fn u8(self) -> u8;
+
+    /// Returns the lower 16 bits.
+    fn u16(self) -> u16;
+
+    /// Returns the lower 32 bits.
+    fn u32(self) -> u32;
+
+    /// Returns the lower 64 bits.
+    fn u64(self) -> u64;
+}
+
+/// Bitwise logic, shifts, rotations and bit manipulation.
+pub trait BitOps: EintBase {
+    /// Get bit.
+    fn bit(&self, n: u32) -> bool;
+
+    /// Clear bit.
+    fn bit_clr(&mut self, n: u32);
+
+    /// Set bit.
+    fn bit_set(&mut self, n: u32);
+
+    /// Reverses the order of bits in each byte.
+    fn brev8(self) -> Self;
+
+    /// Returns the number of leading zeros in the binary representation of self.
+    fn clz(&self) -> u32;
+
+    /// Carry-less multiplication. Returns the lower part of the carry-less product.
+    fn clmul(self, other: Self) -> Self {
+        self.widening_clmul(other).0
+    }
+
+    /// Carry-less multiplication. Returns the higher part of the carry-less product.
+    fn clmulh(self, other: Self) -> Self {
+        self.widening_clmul(other).1
+    }
+
+    /// Returns the number of ones in the binary representation of self.
+    fn cpop(&self) -> u32;
+
+    /// Returns the number of ones in the binary representation of self at the positions of the set bits in mask.
+    fn cpop_under(&self, mask: &Self) -> u32 {
+        (*self & *mask).cpop()
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of self.
+    fn ctz(&self) -> u32;
+
+    /// Returns the len bits starting at bit start, moved to the lower part. Bits beyond the bitwidth of the type read
+    /// as zero.
+    fn extract_bits(self, start: u32, len: u32) -> Self {
+        if start >= Self::BITS {
+            Self::MIN_U
+        } else {
+            self.wrapping_shr(start) & Self::mask(len)
+        }
+    }
+
+    /// Returns the index of the first set bit, or None if no bit is set. Equivalent to vfirst.m.
+    fn first_set(&self) -> Option<u32> {
+        self.lowest_set_bit()
+    }
+
+    /// Returns the number of bit positions at which self and other differ.
+    fn hamming_distance(&self, other: &Self) -> u32 {
+        (*self ^ *other).cpop()
+    }
+
+    /// Returns the index of the highest set bit, or None if self is zero.
+    fn highest_set_bit(&self) -> Option<u32> {
+        if *self == Self::MIN_U {
+            None
+        } else {
+            Some(Self::BITS - 1 - self.clz())
+        }
+    }
+
+    /// Replaces the len bits starting at bit start with the lower len bits of other. Bits that would be placed beyond
+    /// the bitwidth of the type are discarded.
+    fn insert_bits(self, start: u32, len: u32, other: Self) -> Self {
+        if start >= Self::BITS {
+            self
+        } else {
+            let mask = Self::mask(len).wrapping_shl(start);
+            (self & !mask) | (other.wrapping_shl(start) & mask)
+        }
+    }
+
+    /// Returns an iterator over all bit positions, yielding the number of set bits below each position. Equivalent
+    /// to viota.m.
+    fn iota(&self) -> Iota<Self> {
+        Iota { data: *self, n: 0, r: 0 }
+    }
+
+    /// Returns an iterator over the indices of the set bits, from the lowest to the highest.
+    fn iter_ones(&self) -> IterOnes<Self> {
+        IterOnes { data: *self }
+    }
+
+    /// Returns the number of leading ones in the binary representation of self.
+    fn leading_ones(&self) -> u32;
+
+    /// Returns the number of bits following the highest bit that are equal to it. The highest bit itself is not
+    /// counted, so the result is in 0..BITS.
+    fn leading_sign_bits(&self) -> u32 {
+        if self.bit(Self::BITS - 1) {
+            self.leading_ones() - 1
+        } else {
+            self.clz() - 1
+        }
+    }
+
+    /// Returns the index of the lowest set bit, or None if self is zero.
+    fn lowest_set_bit(&self) -> Option<u32> {
+        if *self == Self::MIN_U {
+            None
+        } else {
+            Some(self.ctz())
+        }
+    }
+
+    /// Returns an integer with the lower n bits set. If n is not less than the bitwidth of the type, all bits are set.
+    fn mask(n: u32) -> Self {
+        if n >= Self::BITS {
+            Self::MAX_U
+        } else {
+            !Self::MAX_U.wrapping_shl(n)
+        }
+    }
+
+    /// Returns true if the number of ones in the binary representation of self is odd.
+    fn parity(&self) -> bool {
+        self.cpop() & 1 != 0
+    }
+
+    /// Parallel bits deposit. Scatters the lower bits of self to the positions of the set bits in mask, from the lowest
+    /// to the highest.
+    fn pdep(self, mask: Self) -> Self;
+
+    /// Parallel bits extract. Gathers the bits of self at the positions of the set bits in mask into the lower part.
+    fn pext(self, mask: Self) -> Self;
+
+    /// Reverses the order of bits in the integer.
+    fn reverse_bits(self) -> Self;
+
+    /// Shifts the bits to the left by a specified amount, wrapping the truncated bits to the end of the resulting
+    /// integer. The amount is taken modulo the bitwidth of the type.
+    fn rotate_left(self, other: u32) -> Self;
+
+    /// Shifts the bits to the right by a specified amount, wrapping the truncated bits to the beginning of the
+    /// resulting integer. The amount is taken modulo the bitwidth of the type.
+    fn rotate_right(self, other: u32) -> Self;
+
+    /// Sets all bits before the first set bit and clears the others. If no bit is set, all bits are set. Equivalent
+    /// to vmsbf.m.
+    fn set_before_first(self) -> Self {
+        (self - Self::ONE) & !self
+    }
+
+    /// Sets all bits up to and including the first set bit and clears the others. If no bit is set, all bits are set.
+    /// Equivalent to vmsif.m.
+    fn set_including_first(self) -> Self {
+        (self - Self::ONE) ^ self
+    }
+
+    /// Keeps only the first set bit. Equivalent to vmsof.m.
+    fn set_only_first(self) -> Self {
+        self & -self
+    }
+
+    /// Sign extended.
+    fn sext(self, other: u32) -> Self {
+        self.wrapping_shl(Self::BITS - other - 1).wrapping_sra(Self::BITS - other - 1)
+    }
+
+    /// Reverses the byte order of the integer.
+    fn swap_bytes(self) -> Self;
+
+    /// Returns the number of trailing ones in the binary representation of self.
+    fn trailing_ones(&self) -> u32;
+
+    /// Widening carry-less multiplication.
+    /// (lo, hi) = x * y in GF(2)[x] with the product bits' upper half returned in hi and the lower half returned in lo.
+    fn widening_clmul(self, other: Self) -> (Self, Self);
+
+    /// Panic-free bitwise shift-left; yields self << mask(rhs), where mask removes any high-order bits of rhs that
+    /// would cause the shift to exceed the bitwidth of the type.
+    fn wrapping_shl(self, other: u32) -> Self;
+
+    /// Panic-free bitwise shift-right; yields self >> mask(rhs), where mask removes any high-order bits of rhs that
+    /// would cause the shift to exceed the bitwidth of the type.
+    fn wrapping_shr(self, other: u32) -> Self;
+
+    /// Panic-free bitwise sign shift-right.
+    fn wrapping_sra(self, other: u32) -> Self;
+
+    /// Zero extended.
+    fn zext(self, other: u32) -> Self {
+        self.wrapping_shl(Self::BITS - other - 1).wrapping_shr(Self::BITS - other - 1)
+    }
+}
+
+/// Arithmetic and comparison on unsigned integers.
+pub trait UnsignedArith: EintBase {
+    /// Returns (self + rhs) >> 1.
+    fn average_add_u(self, other: Self) -> Self {
+        (self & other).wrapping_add((self ^ other) >> 1)
+    }
+
+    /// Returns (self - rhs) >> 1.
+    fn average_sub_u(self, other: Self) -> Self {
+        let (lo, borrow) = self.overflowing_sub_u(other);
+        if borrow {
+            (lo >> 1) | (Self::ONE << (Self::BITS - 1))
+        } else {
+            lo >> 1
+        }
+    }
+
+    /// Calculates self - other - borrow, returning the difference and the output borrow. Chains into a multi-word
+    /// subtraction.
+    fn borrowing_sub(self, other: Self, borrow: bool) -> (Self, bool);
+
+    /// Calculates self + other + carry, returning the sum and the output carry. Chains into a multi-word addition.
+    fn carrying_add(self, other: Self, carry: bool) -> (Self, bool);
+
+    /// Compare.
+    fn cmp_u(&self, other: &Self) -> core::cmp::Ordering;
+
+    /// Calculates self + rhs.
+    fn overflowing_add_u(self, other: Self) -> (Self, bool);
+
+    /// Fused multiply-add. Computes self * other + acc, wrapping around at the boundary of the type.
+    fn mul_add(self, other: Self, acc: Self) -> Self {
+        self.wrapping_mul(other).wrapping_add(acc)
+    }
+
+    /// Fused negated multiply-add. Computes acc - self * other, wrapping around at the boundary of the type.
+    fn mul_sub_neg(self, other: Self, acc: Self) -> Self {
+        self.mul_add(-other, acc)
+    }
+
+    /// Calculates self * rhs.
+    fn overflowing_mul_u(self, other: Self) -> (Self, bool);
+
+    /// Calculates self - rhs.
+    fn overflowing_sub_u(self, other: Self) -> (Self, bool);
+
+    /// Saturating integer addition. Computes self + rhs, saturating at the numeric bounds instead of overflowing.
+    fn saturating_add_u(self, other: Self) -> (Self, bool) {
+        let (r, overflow) = self.overflowing_add_u(other);
+        if overflow {
+            (Self::MAX_U, overflow)
+        } else {
+            (r, overflow)
+        }
+    }
+
+    /// Saturating integer subtraction. Computes self - rhs, saturating at the numeric bounds instead of overflowing.
+    fn saturating_sub_u(self, other: Self) -> (Self, bool) {
+        if self >= other {
+            (self.wrapping_sub(other), false)
+        } else {
+            (Self::MIN_U, true)
+        }
+    }
+
+    /// Wrapping (modular) addition. Computes self + rhs, wrapping around at the boundary of the type.
+    fn wrapping_add(self, other: Self) -> Self;
+
+    /// Wrapping (modular) division. Computes self / rhs.
+    /// 1) x / 0 = MAX_U
+    fn wrapping_div_u(self, other: Self) -> Self;
+
+    /// Wrapping (modular) multiplication. Computes self * rhs, wrapping around at the boundary of the type.
+    fn wrapping_mul(self, other: Self) -> Self;
+
+    /// Wrapping (modular) remainder.
+    /// 1) x % 0 = x
+    fn wrapping_rem_u(self, other: Self) -> Self;
+
+    /// Wrapping (modular) subtraction. Computes self - rhs, wrapping around at the boundary of the type.
+    fn wrapping_sub(self, other: Self) -> Self;
+}
+
+/// Arithmetic and comparison on two's complement signed integers.
+pub trait SignedArith: EintBase {
+    /// Returns (self + rhs) >> 1. Signed.
+    fn average_add_s(self, other: Self) -> Self {
+        let x = self ^ other;
+        (self & other) + ((x >> 1) | (x & Self::MIN_S))
+    }
+
+    /// Returns (self - rhs) >> 1. Signed.
+    fn average_sub_s(self, other: Self) -> Self {
+        let (lo, borrow) = sbb(self, other, false);
+        let hi_0 = if !self.is_negative() { Self::MIN_U } else { Self::MAX_U };
+        let hi_1 = if !other.is_negative() { Self::MIN_U } else { Self::MAX_U };
+        let hi = hi_0 - hi_1 - Self::from(borrow);
+        (lo >> 1) | (hi << (Self::BITS - 1))
+    }
+
+    /// Compare. Signed.
+    fn cmp_s(&self, other: &Self) -> core::cmp::Ordering;
+
+    /// Returns true if highest bit is set.
+    fn is_negative(&self) -> bool;
+
+    /// Returns true if highest bit is not set.
+    fn is_positive(&self) -> bool;
+
+    /// Calculates self + rhs. Signed.
+    fn overflowing_add_s(self, other: Self) -> (Self, bool);
+
+    /// Calculates self * rhs. Signed.
+    fn overflowing_mul_s(self, other: Self) -> (Self, bool);
+
+    /// Calculates self - rhs. Signed.
+    fn overflowing_sub_s(self, other: Self) -> (Self, bool);
+
+    /// Saturating integer addition. Computes self + rhs, saturating at the numeric bounds instead of overflowing. Signed.
+    fn saturating_add_s(self, other: Self) -> (Self, bool) {
+        let r = self + other;
+        if !(self ^ other).is_negative() {
+            if (r ^ self).is_negative() {
+                let r = if self.is_negative() { Self::MIN_S } else { Self::MAX_S };
+                return (r, true);
+            }
+        }
+        (r, false)
+    }
+
+    /// Saturating integer subtraction. Computes self - rhs, saturating at the numeric bounds instead of overflowing. Signed.
+    fn saturating_sub_s(self, other: Self) -> (Self, bool) {
+        let r = self - other;
+        if (self ^ other).is_negative() {
+            if (r ^ self).is_negative() {
+                let r = if self.is_negative() { Self::MIN_S } else { Self::MAX_S };
+                return (r, true);
+            }
+        }
+        (r, false)
+    }
+
+    /// Wrapping (modular) division. Signed.
+    /// 1) x / 0 = -1.
+    /// 2) MIN_S / -1 = MIN_S
+    fn wrapping_div_s(self, other: Self) -> Self;
+
+    /// Wrapping (modular) remainder. Signed.
+    /// 1) x % 0 = x
+    /// 2) MIN_S % -1 = 0
+    fn wrapping_rem_s(self, other: Self) -> Self;
+}
+
+/// Operations returning a double width result as a (lo, hi) pair.
+pub trait Widening: EintBase {
+    /// Returns the higher part.
+    fn hi(self) -> Self;
+
+    /// Returns the lower part.
+    fn lo(self) -> Self;
+
+    /// Returns the lower part and sign extend it.
+    fn lo_sext(self) -> Self;
+
+    /// Widening add. Signed.
+    /// (lo, hi) = x + y with the product bits' upper half returned in hi and the lower half returned in lo.
+    fn widening_add_s(self, other: Self) -> (Self, Self) {
+        let hi_0 = if msb(self) { Self::MAX_U } else { Self::MIN_U };
+        let hi_1 = if msb(other) { Self::MAX_U } else { Self::MIN_U };
+        let (lo, carry) = adc(self, other, false);
+        let hi = hi_0 + hi_1 + Self::from(carry);
+        (lo, hi)
+    }
+
+    /// Widening add.
+    /// (lo, hi) = x + y with the product bits' upper half returned in hi and the lower half returned in lo.
+    fn widening_add_u(self, other: Self) -> (Self, Self) {
+        let (lo, carry) = adc(self, other, false);
+        (lo, Self::from(carry))
+    }
+
+    /// Widening multiple. Signed.
+    ///
+    /// (lo, hi) = x * y with the product bits' upper half returned in hi and the lower half returned in lo.
+    /// Inspired by https://sqlite.in/?qa=668884/c-32-bit-signed-integer-multiplication-without-using-64-bit-data-type
+    fn widening_mul_s(self, other: Self) -> (Self, Self) {
+        let (lo, hi) = self.widening_mul_u(other);
+        let hi = hi - if msb(self) { other } else { Self::MIN_U } - if msb(other) { self } else { Self::MIN_U };
+        (lo, hi)
+    }
+
+    /// Widening multiply-accumulate. Signed.
+    /// (lo, hi) = x * y + (acc_lo, acc_hi), wrapping around at the boundary of the double width type.
+    fn widening_mul_add_s(self, other: Self, acc_lo: Self, acc_hi: Self) -> (Self, Self) {
+        let (lo, hi) = self.widening_mul_add_u(other, acc_lo, acc_hi);
+        let hi = hi - if msb(self) { other } else { Self::MIN_U } - if msb(other) { self } else { Self::MIN_U };
+        (lo, hi)
+    }
+
+    /// Widening multiply-accumulate of a signed self and an unsigned other.
+    /// (lo, hi) = x * y + (acc_lo, acc_hi), wrapping around at the boundary of the double width type.
+    fn widening_mul_add_su(self, other: Self, acc_lo: Self, acc_hi: Self) -> (Self, Self) {
+        let (lo, hi) = self.widening_mul_add_u(other, acc_lo, acc_hi);
+        let hi = hi - if msb(self) { other } else { Self::MIN_U };
+        (lo, hi)
+    }
+
+    /// Widening multiply-accumulate.
+    /// (lo, hi) = x * y + (acc_lo, acc_hi), wrapping around at the boundary of the double width type.
+    fn widening_mul_add_u(self, other: Self, acc_lo: Self, acc_hi: Self) -> (Self, Self) {
+        let (lo, hi) = self.widening_mul_u(other);
+        let (lo, carry) = adc(lo, acc_lo, false);
+        (lo, hi + acc_hi + Self::from(carry))
+    }
+
+    /// Widening multiply-accumulate of an unsigned self and a signed other.
+    /// (lo, hi) = x * y + (acc_lo, acc_hi), wrapping around at the boundary of the double width type.
+    fn widening_mul_add_us(self, other: Self, acc_lo: Self, acc_hi: Self) -> (Self, Self) {
+        other.widening_mul_add_su(self, acc_lo, acc_hi)
+    }
+
+    /// Widening signed and unsigned integer multiply.
+    /// (lo, hi) = x * y with the product bits' upper half returned in hi and the lower half returned in lo.
+    fn widening_mul_su(self, other: Self) -> (Self, Self) {
+        if !msb(other) {
+            self.widening_mul_s(other)
+        } else {
+            let (lo, hi) = self.widening_mul_s(other);
+            let hi = hi + self;
+            (lo, hi)
+        }
+    }
+
+    /// Widening multiple.
+    /// (lo, hi) = x * y with the product bits' upper half returned in hi and the lower half returned in lo.
+    fn widening_mul_u(self, other: Self) -> (Self, Self);
+
+    /// Widening substract. Signed.
+    /// (lo, hi) = x - y with the product bits' upper half returned in hi and the lower half returned in lo.
+    fn widening_sub_s(self, other: Self) -> (Self, Self) {
+        let hi_0 = if msb(self) { Self::MAX_U } else { Self::MIN_U };
+        let hi_1 = if msb(other) { Self::MAX_U } else { Self::MIN_U };
+        let (lo, borrow) = sbb(self, other, false);
+        let hi = hi_0 - hi_1 - Self::from(borrow);
+        (lo, hi)
+    }
+
+    /// Widening substract.
+    /// (lo, hi) = x - y with the product bits' upper half returned in hi and the lower half returned in lo.
+    fn widening_sub_u(self, other: Self) -> (Self, Self) {
+        let (lo, borrow) = sbb(self, other, false);
+        (lo, if borrow { Self::MAX_U } else { Self::MIN_U })
+    }
+}
+
+/// Constant-time comparison, selection and arithmetic. The methods work limb by limb without data-dependent branches,
+/// computing carries and borrows with bit masks, and pass comparison results through [`core::hint::black_box`] so
+/// that the compiler does not turn them back into branches. Choices are represented as a u8 that is 1 for true and 0
+/// for false, as in `subtle::Choice`; any other value gives an unspecified result.
+pub trait ConstantTime: EintBase {
+    /// Wrapping (modular) addition.
+    fn ct_add(self, other: Self) -> Self {
+        let mut r = Self::ZERO;
+        let mut carry = 0;
+        for i in 0..Self::LIMBS {
+            let (x, y) = (self.limb(i), other.limb(i));
+            let s = x.wrapping_add(y).wrapping_add(carry);
+            carry = ((x & y) | ((x | y) & !s)) >> 63;
+            r.set_limb(i, s);
+        }
+        r
+    }
+
+    /// Returns 1 if self equals other, otherwise 0.
+    fn ct_eq(&self, other: &Self) -> u8 {
+        let mut x = 0;
+        for i in 0..Self::LIMBS {
+            x |= self.limb(i) ^ other.limb(i);
+        }
+        core::hint::black_box((((x | x.wrapping_neg()) >> 63) ^ 1) as u8)
+    }
+
+    /// Returns 1 if self is less than other, otherwise 0. Signed.
+    fn ct_lt_s(&self, other: &Self) -> u8 {
+        (*self ^ Self::MIN_S).ct_lt_u(&(*other ^ Self::MIN_S))
+    }
+
+    /// Returns 1 if self is less than other, otherwise 0.
+    fn ct_lt_u(&self, other: &Self) -> u8 {
+        let mut borrow = 0;
+        for i in 0..Self::LIMBS {
+            let (x, y) = (self.limb(i), other.limb(i));
+            let d = x.wrapping_sub(y).wrapping_sub(borrow);
+            borrow = ((!x & y) | (!(x ^ y) & d)) >> 63;
+        }
+        core::hint::black_box(borrow as u8)
+    }
+
+    /// Wrapping (modular) multiplication. Every limb product is computed, whatever the values of the limbs.
+    fn ct_mul(self, other: Self) -> Self {
+        let mut r = [0u64; 32];
+        for i in 0..Self::LIMBS {
+            let mut carry = 0u64;
+            for j in 0..Self::LIMBS - i {
+                let t = r[i + j] as u128 + self.limb(i) as u128 * other.limb(j) as u128 + carry as u128;
+                r[i + j] = t as u64;
+                carry = (t >> 64) as u64;
+            }
+        }
+        Self::from_limbs(&r[..Self::LIMBS])
+    }
+
+    /// Returns a if choice is 1, or b if choice is 0.
+    fn ct_select(choice: u8, a: Self, b: Self) -> Self {
+        let mask = 0u64.wrapping_sub(core::hint::black_box(choice) as u64);
+        let mut r = b;
+        for i in 0..Self::LIMBS {
+            r.set_limb(i, b.limb(i) ^ (mask & (a.limb(i) ^ b.limb(i))));
+        }
+        r
+    }
+
+    /// Wrapping (modular) subtraction.
+    fn ct_sub(self, other: Self) -> Self {
+        let mut r = Self::ZERO;
+        let mut borrow = 0;
+        for i in 0..Self::LIMBS {
+            let (x, y) = (self.limb(i), other.limb(i));
+            let d = x.wrapping_sub(y).wrapping_sub(borrow);
+            borrow = ((!x & y) | (!(x ^ y) & d)) >> 63;
+            r.set_limb(i, d);
+        }
+        r
+    }
+
+    /// Swaps self and other if choice is 1, and leaves them unchanged if choice is 0.
+    fn ct_swap(&mut self, other: &mut Self, choice: u8) {
+        let mask = 0u64.wrapping_sub(core::hint::black_box(choice) as u64);
+        for i in 0..Self::LIMBS {
+            let t = mask & (self.limb(i) ^ other.limb(i));
+            self.set_limb(i, self.limb(i) ^ t);
+            other.set_limb(i, other.limb(i) ^ t);
+        }
+    }
+}
+
+impl<T: EintBase> ConstantTime for T {}
+
+/// Access to the integer as a vector of narrower lanes, and lane-wise SWAR (SIMD within a register) arithmetic on the
+/// u64 limbs.
+pub trait LaneOps: EintBase {
+    /// Returns the i-th lane of type L, where lane 0 holds the least significant bits. Panics if i is not less than
+    /// Self::BITS / L::WIDTH.
+    fn lane<L: Lane>(&self, i: usize) -> L {
+        assert!(i < (Self::BITS / L::WIDTH) as usize, "lane index out of bounds");
+        let n = i * L::WIDTH as usize;
+        L::from_limb(self.limb(n >> 6) >> (n & 63))
+    }
+
+    /// Returns an iterator over the lanes of type L, starting from the least significant one. Panics if L is wider
+    /// than self.
+    fn lanes<L: Lane>(&self) -> Lanes<Self, L> {
+        assert!(L::WIDTH <= Self::BITS, "lane wider than integer");
+        Lanes { data: *self, i: 0, n: (Self::BITS / L::WIDTH) as usize, lane: core::marker::PhantomData }
+    }
+
+    /// Lane-wise wrapping addition of the lanes of type L. Panics if L is wider than self.
+    fn lanes_add<L: Lane>(self, other: Self) -> Self {
+        map_lanes::<Self, L>(self, other, swar_add)
+    }
+
+    /// Compares the lanes of type L of self and other. Returns a mask with bit i set if the i-th lanes are equal.
+    /// Panics if L is wider than self.
+    fn lanes_cmp_eq<L: Lane>(self, other: Self) -> Self {
+        let e = map_lanes::<Self, L>(self, other, |x, y, h| {
+            let t = x ^ y;
+            !(((t & !h) + !h) | t) & h
+        });
+        // A limb holds k <= 8 lanes of w >= 8 bits. After shifting the lane MSBs down to bits w * j, the multiplier
+        // moves bit j to bit 64 - k + j; no two partial products share a bit, so nothing carries into the result.
+        let (w, k) = (L::WIDTH, 64 / L::WIDTH);
+        let m = (((1u64 << ((w - 1) * k)) - 1) / ((1u64 << (w - 1)) - 1)) << (w - 1);
+        let mut r = Self::ZERO;
+        for i in 0..Self::LIMBS {
+            let g = (e.limb(i) >> (w - 1)).wrapping_mul(m) >> (64 - k);
+            let n = i * k as usize;
+            r.set_limb(n >> 6, r.limb(n >> 6) | g << (n & 63));
+        }
+        r
+    }
+
+    /// Lane-wise signed maximum of the lanes of type L. Panics if L is wider than self.
+    fn lanes_max_s<L: Lane>(self, other: Self) -> Self {
+        map_lanes::<Self, L>(self, other, |x, y, h| {
+            let m = swar_lt_u(x ^ h, y ^ h, h);
+            y & m | x & !m
+        })
+    }
+
+    /// Lane-wise unsigned maximum of the lanes of type L. Panics if L is wider than self.
+    fn lanes_max_u<L: Lane>(self, other: Self) -> Self {
+        map_lanes::<Self, L>(self, other, |x, y, h| {
+            let m = swar_lt_u(x, y, h);
+            y & m | x & !m
+        })
+    }
+
+    /// Lane-wise signed minimum of the lanes of type L. Panics if L is wider than self.
+    fn lanes_min_s<L: Lane>(self, other: Self) -> Self {
+        map_lanes::<Self, L>(self, other, |x, y, h| {
+            let m = swar_lt_u(x ^ h, y ^ h, h);
+            x & m | y & !m
+        })
+    }
+
+    /// Lane-wise unsigned minimum of the lanes of type L. Panics if L is wider than self.
+    fn lanes_min_u<L: Lane>(self, other: Self) -> Self {
+        map_lanes::<Self, L>(self, other, |x, y, h| {
+            let m = swar_lt_u(x, y, h);
+            x & m | y & !m
+        })
+    }
+
+    /// Shifts each lane of type L left by other modulo L::WIDTH bits. Panics if L is wider than self.
+    fn lanes_shl<L: Lane>(self, other: u32) -> Self {
+        let n = other & (L::WIDTH - 1);
+        map_lanes::<Self, L>(self, self, |x, _, h| {
+            let m = u64::MAX >> (64 - L::WIDTH);
+            (x << n) & ((h >> (L::WIDTH - 1)) * ((m << n) & m))
+        })
+    }
+
+    /// Lane-wise wrapping subtraction of the lanes of type L. Panics if L is wider than self.
+    fn lanes_sub<L: Lane>(self, other: Self) -> Self {
+        map_lanes::<Self, L>(self, other, swar_sub)
+    }
+
+    /// Sets the i-th lane of type L. Panics if i is not less than Self::BITS / L::WIDTH.
+    fn set_lane<L: Lane>(&mut self, i: usize, v: L) {
+        assert!(i < (Self::BITS / L::WIDTH) as usize, "lane index out of bounds");
+        let n = i * L::WIDTH as usize;
+        let m = u64::MAX >> (64 - L::WIDTH);
+        let x = self.limb(n >> 6) & !(m << (n & 63)) | v.to_limb() << (n & 63);
+        self.set_limb(n >> 6, x);
+    }
+}
+
+impl<T: EintBase> LaneOps for T {}
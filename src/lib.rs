@@ -6,16 +6,73 @@
 //! [dependencies]
 //! eint = "0.1"
 //! ```
+//!
+//! # Layout
+//!
+//! All integer types are `#[repr(transparent)]`. E8 to E128 have the same layout as the primitive unsigned integer
+//! they wrap, E256 to E2048 have the same layout as their `[u64; N]` limb array, with the least significant limb first.
+//! Each limb uses the native byte order, so on little-endian targets the memory of an integer equals its
+//! little-endian byte representation.
 
 #![no_std]
 
-pub trait EintWideningMulU: Sized {
-    fn _widening_mul_u(self, other: Self) -> (Self, Self);
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "ethnum")]
+mod ethnum;
+pub mod layers;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "primitive-types")]
+mod primitive_types;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rand")]
+pub mod rand;
+#[cfg(feature = "ruint")]
+mod ruint;
+#[cfg(feature = "scale")]
+mod scale;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "subtle")]
+mod subtle;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(feature = "num-traits")]
+pub use crate::num_traits::ParseEintError;
+#[cfg(feature = "zeroize")]
+pub use crate::zeroize::Secret;
+
+/// Carry-less multiplication of two little-endian limb slices. The result is xored into r, which must be at least
+/// x.len() + y.len() limbs long.
+fn clmul_limbs(x: &[u64], y: &[u64], r: &mut [u64]) {
+    for i in 0..y.len() {
+        for j in 0..64 {
+            let m = 0u64.wrapping_sub((y[i] >> j) & 1);
+            for k in 0..x.len() {
+                r[i + k] ^= (x[k] << j) & m;
+                if j != 0 {
+                    r[i + k + 1] ^= (x[k] >> (64 - j)) & m;
+                }
+            }
+        }
+    }
 }
 
 macro_rules! impl_widening_mul_u_wrap {
     ($eint:ty, $wint:ty) => {
-        impl EintWideningMulU for $eint {
+        impl $eint {
             fn _widening_mul_u(self, other: Self) -> (Self, Self) {
                 let lh = (self.0 as $wint) * (other.0 as $wint);
                 let l = Self::from(lh);
@@ -28,7 +85,7 @@ macro_rules! impl_widening_mul_u_wrap {
 
 macro_rules! impl_widening_mul_u_twin {
     ($eint:ty, $size:expr) => {
-        impl EintWideningMulU for $eint {
+        impl $eint {
             fn _widening_mul_u(self, other: Self) -> (Self, Self) {
                 let mut lh = [0u64; $size * 2];
                 for i in 0..$size {
@@ -51,322 +108,1103 @@ macro_rules! impl_widening_mul_u_twin {
     };
 }
 
-pub trait Eint:
-    Clone
-    + Copy
-    + Default
-    + Eq
-    + From<bool>
-    + From<i8>
-    + From<i16>
-    + From<i32>
-    + From<i64>
-    + From<i128>
-    + From<u8>
-    + From<u16>
-    + From<u32>
-    + From<u64>
-    + From<u128>
-    + From<Self>
-    + PartialEq
-    + core::cmp::Ord
-    + core::cmp::PartialOrd
-    + core::fmt::Debug
-    + core::fmt::Display
-    + core::fmt::LowerHex
-    + core::ops::Add<Output = Self>
-    + core::ops::AddAssign
-    + core::ops::BitAnd<Output = Self>
-    + core::ops::BitAndAssign
-    + core::ops::BitOr<Output = Self>
-    + core::ops::BitOrAssign
-    + core::ops::BitXor<Output = Self>
-    + core::ops::BitXorAssign
-    + core::ops::Div<Output = Self>
-    + core::ops::DivAssign
-    + core::ops::Mul<Output = Self>
-    + core::ops::MulAssign
-    + core::ops::Neg<Output = Self>
-    + core::ops::Not
-    + core::ops::Rem<Output = Self>
-    + core::ops::RemAssign
-    + core::ops::Sub<Output = Self>
-    + core::ops::SubAssign
-    + core::ops::Shl<u32, Output = Self>
-    + core::ops::Shr<u32, Output = Self>
-    + EintWideningMulU
-{
-    const BITS: u32;
-    const MAX_S: Self;
-    const MAX_U: Self;
-    const MIN_S: Self;
-    const MIN_U: Self;
-    const ONE: Self;
-    const ZERO: Self;
-
-    /// Returns (self + rhs) >> 1. Signed.
-    fn average_add_s(self, other: Self) -> Self {
-        (self & other).wrapping_add((self ^ other).wrapping_sra(1))
-    }
-
-    /// Returns (self + rhs) >> 1.
-    fn average_add_u(self, other: Self) -> Self {
-        (self & other).wrapping_add((self ^ other).wrapping_shr(1))
-    }
-
-    /// Returns (self - rhs) >> 1. Signed.
-    fn average_sub_s(self, other: Self) -> Self {
-        let (lo, borrow) = self.overflowing_sub_u(other);
-        let hi_0 = if !self.is_negative() { Self::MIN_U } else { Self::MAX_U };
-        let hi_1 = if !other.is_negative() { Self::MIN_U } else { Self::MAX_U };
-        let hi = hi_0.wrapping_sub(hi_1).wrapping_sub(Self::from(borrow));
-        lo.wrapping_shr(1) | hi.wrapping_shl(1).wrapping_shl(Self::BITS - 2)
-    }
-
-    /// Returns (self - rhs) >> 1.
-    fn average_sub_u(self, other: Self) -> Self {
-        let (lo, borrow) = self.overflowing_sub_u(other);
-        if borrow {
-            (lo >> 1) | (Self::ONE << (Self::BITS - 1))
-        } else {
-            lo >> 1
+mod private {
+    pub trait Sealed {}
+}
+
+/// Element types that an integer can be split into. This trait is sealed and implemented for u8, u16, u32, u64 and
+/// E8, E16, E32, E64.
+pub trait Lane: Copy + private::Sealed {
+    /// The width of the lane in bits.
+    const WIDTH: u32;
+
+    /// Returns the lower Self::WIDTH bits of x.
+    fn from_limb(x: u64) -> Self;
+
+    /// Zero-extends self to a u64.
+    fn to_limb(self) -> u64;
+}
+
+macro_rules! construct_lane {
+    ($name:ty) => {
+        impl private::Sealed for $name {}
+
+        impl Lane for $name {
+            const WIDTH: u32 = <$name>::BITS;
+
+            fn from_limb(x: u64) -> Self {
+                x as $name
+            }
+
+            fn to_limb(self) -> u64 {
+                self as u64
+            }
         }
+    };
+}
+
+construct_lane!(u8);
+construct_lane!(u16);
+construct_lane!(u32);
+construct_lane!(u64);
+
+/// Calls a method of a layered trait with the parameters of the forwarding method.
+macro_rules! forward_eint_call {
+    ($tr:ident, $name:ident, [$($g:ident)?], &mut $s:ident $(, $a:ident: $t:ty)*) => {
+        <Self as layers::$tr>::$name$(::<$g>)?($s $(, $a)*)
+    };
+    ($tr:ident, $name:ident, [$($g:ident)?], & $s:ident $(, $a:ident: $t:ty)*) => {
+        <Self as layers::$tr>::$name$(::<$g>)?($s $(, $a)*)
+    };
+    ($tr:ident, $name:ident, [$($g:ident)?], $s:ident $(, $a:ident: $t:ty)*) => {
+        <Self as layers::$tr>::$name$(::<$g>)?($s $(, $a)*)
+    };
+    ($tr:ident, $name:ident, [$($g:ident)?], $($a:ident: $t:ty),*) => {
+        <Self as layers::$tr>::$name$(::<$g>)?($($a),*)
+    };
+}
+
+macro_rules! construct_eint {
+    (
+        consts { $(const $c:ident: $ct:ty;)* }
+        $($tr:ident {
+            $($(#[$m:meta])* fn $name:ident $(<$g:ident: $gb:ident>)? ($($args:tt)*) $(-> $r:ty)?;)*
+        })*
+    ) => {
+        /// All integer operations. Every item forwards to the trait of the [`layers`] module it is named after, and
+        /// Eint is implemented for every type implementing all of them. Generic code that only needs part of the
+        /// operations may bound on those traits instead.
+        pub trait Eint:
+            Clone
+            + Copy
+            + Default
+            + Eq
+            + From<bool>
+            + From<i8>
+            + From<i16>
+            + From<i32>
+            + From<i64>
+            + From<i128>
+            + From<u8>
+            + From<u16>
+            + From<u32>
+            + From<u64>
+            + From<u128>
+            + From<Self>
+            + PartialEq
+            + core::cmp::Ord
+            + core::cmp::PartialOrd
+            + core::fmt::Debug
+            + core::fmt::Display
+            + core::fmt::LowerHex
+            + core::ops::Add<Output = Self>
+            + core::ops::AddAssign
+            + core::ops::BitAnd<Output = Self>
+            + core::ops::BitAndAssign
+            + core::ops::BitOr<Output = Self>
+            + core::ops::BitOrAssign
+            + core::ops::BitXor<Output = Self>
+            + core::ops::BitXorAssign
+            + core::ops::Div<Output = Self>
+            + core::ops::DivAssign
+            + core::ops::Mul<Output = Self>
+            + core::ops::MulAssign
+            + core::ops::Neg<Output = Self>
+            + core::ops::Not<Output = Self>
+            + core::ops::Rem<Output = Self>
+            + core::ops::RemAssign
+            + core::ops::Sub<Output = Self>
+            + core::ops::SubAssign
+            + core::ops::Shl<u32, Output = Self>
+            + core::ops::Shr<u32, Output = Self>
+        {
+            $(
+                #[doc = concat!("See [`layers::EintBase::", stringify!($c), "`].")]
+                const $c: $ct;
+            )*
+            $($(
+                $(#[$m])*
+                #[doc = concat!("See [`layers::", stringify!($tr), "::", stringify!($name), "`].")]
+                fn $name $(<$g: $gb>)? ($($args)*) $(-> $r)?;
+            )*)*
+        }
+
+        impl<T> Eint for T
+        where
+            T: layers::EintBase
+                + layers::BitOps
+                + layers::UnsignedArith
+                + layers::SignedArith
+                + layers::Widening
+                + layers::ConstantTime
+                + layers::LaneOps,
+        {
+            $(const $c: $ct = <T as layers::EintBase>::$c;)*
+            $($(
+                $(#[$m])*
+                fn $name $(<$g: $gb>)? ($($args)*) $(-> $r)? {
+                    forward_eint_call!($tr, $name, [$($g)?], $($args)*)
+                }
+            )*)*
+        }
+    };
+}
+
+construct_eint! {
+    consts {
+        const BITS: u32;
+        const LIMBS: usize;
+        const MAX_S: Self;
+        const MAX_U: Self;
+        const MIN_S: Self;
+        const MIN_U: Self;
+        const ONE: Self;
+        const ZERO: Self;
+    }
+    EintBase {
+        fn from_limbs(limbs: &[u64]) -> Self;
+        fn get(mem: &[u8]) -> Self;
+        fn get_partial(mem: &[u8], nbytes: usize, ext: Extension) -> Self;
+        fn limb(&self, i: usize) -> u64;
+        fn limbs(&self) -> LimbBuf;
+        fn limbs_mut(&mut self) -> LimbsMut<'_, Self>;
+        fn put(&self, mem: &mut [u8]);
+        fn put_lo(&self, mem: &mut [u8]);
+        fn put_masked(&self, mem: &mut [u8], mask: Self);
+        fn put_partial(&self, mem: &mut [u8], nbytes: usize);
+        fn set_limb(&mut self, i: usize, v: u64);
+        #[cfg(feature = "num-bigint")]
+        fn to_bigint_s(&self) -> ::num_bigint::BigInt;
+        fn u8(self) -> u8;
+        fn u16(self) -> u16;
+        fn u32(self) -> u32;
+        fn u64(self) -> u64;
+    }
+    BitOps {
+        fn bit(&self, n: u32) -> bool;
+        fn bit_clr(&mut self, n: u32);
+        fn bit_set(&mut self, n: u32);
+        fn brev8(self) -> Self;
+        fn clz(&self) -> u32;
+        fn clmul(self, other: Self) -> Self;
+        fn clmulh(self, other: Self) -> Self;
+        fn cpop(&self) -> u32;
+        fn cpop_under(&self, mask: &Self) -> u32;
+        fn ctz(&self) -> u32;
+        fn extract_bits(self, start: u32, len: u32) -> Self;
+        fn first_set(&self) -> Option<u32>;
+        fn hamming_distance(&self, other: &Self) -> u32;
+        fn highest_set_bit(&self) -> Option<u32>;
+        fn insert_bits(self, start: u32, len: u32, other: Self) -> Self;
+        fn iota(&self) -> Iota<Self>;
+        fn iter_ones(&self) -> IterOnes<Self>;
+        fn leading_ones(&self) -> u32;
+        fn leading_sign_bits(&self) -> u32;
+        fn lowest_set_bit(&self) -> Option<u32>;
+        fn mask(n: u32) -> Self;
+        fn parity(&self) -> bool;
+        fn pdep(self, mask: Self) -> Self;
+        fn pext(self, mask: Self) -> Self;
+        fn reverse_bits(self) -> Self;
+        fn rotate_left(self, other: u32) -> Self;
+        fn rotate_right(self, other: u32) -> Self;
+        fn set_before_first(self) -> Self;
+        fn set_including_first(self) -> Self;
+        fn set_only_first(self) -> Self;
+        fn sext(self, other: u32) -> Self;
+        fn swap_bytes(self) -> Self;
+        fn trailing_ones(&self) -> u32;
+        fn widening_clmul(self, other: Self) -> (Self, Self);
+        fn wrapping_shl(self, other: u32) -> Self;
+        fn wrapping_shr(self, other: u32) -> Self;
+        fn wrapping_sra(self, other: u32) -> Self;
+        fn zext(self, other: u32) -> Self;
+    }
+    UnsignedArith {
+        fn average_add_u(self, other: Self) -> Self;
+        fn average_sub_u(self, other: Self) -> Self;
+        fn borrowing_sub(self, other: Self, borrow: bool) -> (Self, bool);
+        fn carrying_add(self, other: Self, carry: bool) -> (Self, bool);
+        fn cmp_u(&self, other: &Self) -> core::cmp::Ordering;
+        fn mul_add(self, other: Self, acc: Self) -> Self;
+        fn mul_sub_neg(self, other: Self, acc: Self) -> Self;
+        fn overflowing_add_u(self, other: Self) -> (Self, bool);
+        fn overflowing_mul_u(self, other: Self) -> (Self, bool);
+        fn overflowing_sub_u(self, other: Self) -> (Self, bool);
+        fn saturating_add_u(self, other: Self) -> (Self, bool);
+        fn saturating_sub_u(self, other: Self) -> (Self, bool);
+        fn wrapping_add(self, other: Self) -> Self;
+        fn wrapping_div_u(self, other: Self) -> Self;
+        fn wrapping_mul(self, other: Self) -> Self;
+        fn wrapping_rem_u(self, other: Self) -> Self;
+        fn wrapping_sub(self, other: Self) -> Self;
+    }
+    SignedArith {
+        fn average_add_s(self, other: Self) -> Self;
+        fn average_sub_s(self, other: Self) -> Self;
+        fn cmp_s(&self, other: &Self) -> core::cmp::Ordering;
+        fn is_negative(&self) -> bool;
+        fn is_positive(&self) -> bool;
+        fn overflowing_add_s(self, other: Self) -> (Self, bool);
+        fn overflowing_mul_s(self, other: Self) -> (Self, bool);
+        fn overflowing_sub_s(self, other: Self) -> (Self, bool);
+        fn saturating_add_s(self, other: Self) -> (Self, bool);
+        fn saturating_sub_s(self, other: Self) -> (Self, bool);
+        fn wrapping_div_s(self, other: Self) -> Self;
+        fn wrapping_rem_s(self, other: Self) -> Self;
+    }
+    Widening {
+        fn hi(self) -> Self;
+        fn lo(self) -> Self;
+        fn lo_sext(self) -> Self;
+        fn widening_add_s(self, other: Self) -> (Self, Self);
+        fn widening_add_u(self, other: Self) -> (Self, Self);
+        fn widening_mul_add_s(self, other: Self, acc_lo: Self, acc_hi: Self) -> (Self, Self);
+        fn widening_mul_add_su(self, other: Self, acc_lo: Self, acc_hi: Self) -> (Self, Self);
+        fn widening_mul_add_u(self, other: Self, acc_lo: Self, acc_hi: Self) -> (Self, Self);
+        fn widening_mul_add_us(self, other: Self, acc_lo: Self, acc_hi: Self) -> (Self, Self);
+        fn widening_mul_s(self, other: Self) -> (Self, Self);
+        fn widening_mul_su(self, other: Self) -> (Self, Self);
+        fn widening_mul_u(self, other: Self) -> (Self, Self);
+        fn widening_sub_s(self, other: Self) -> (Self, Self);
+        fn widening_sub_u(self, other: Self) -> (Self, Self);
+    }
+    ConstantTime {
+        fn ct_add(self, other: Self) -> Self;
+        fn ct_eq(&self, other: &Self) -> u8;
+        fn ct_lt_s(&self, other: &Self) -> u8;
+        fn ct_lt_u(&self, other: &Self) -> u8;
+        fn ct_mul(self, other: Self) -> Self;
+        fn ct_select(choice: u8, a: Self, b: Self) -> Self;
+        fn ct_sub(self, other: Self) -> Self;
+        fn ct_swap(&mut self, other: &mut Self, choice: u8);
+    }
+    LaneOps {
+        fn lane<L: Lane>(&self, i: usize) -> L;
+        fn lanes<L: Lane>(&self) -> Lanes<Self, L>;
+        fn lanes_add<L: Lane>(self, other: Self) -> Self;
+        fn lanes_cmp_eq<L: Lane>(self, other: Self) -> Self;
+        fn lanes_max_s<L: Lane>(self, other: Self) -> Self;
+        fn lanes_max_u<L: Lane>(self, other: Self) -> Self;
+        fn lanes_min_s<L: Lane>(self, other: Self) -> Self;
+        fn lanes_min_u<L: Lane>(self, other: Self) -> Self;
+        fn lanes_shl<L: Lane>(self, other: u32) -> Self;
+        fn lanes_sub<L: Lane>(self, other: Self) -> Self;
+        fn set_lane<L: Lane>(&mut self, i: usize, v: L);
     }
+}
 
-    /// Get bit.
-    fn bit(&self, n: u32) -> bool;
+/// Integers with a type twice as wide. Implemented for E8 to E1024.
+///
+/// The widening operations return a single Double instead of a (lo, hi) pair. The `_wv` forms take a wide operand and
+/// a narrow operand, and extend the narrow one before the operation.
+pub trait Widen: Eint {
+    type Double: Narrow<Half = Self>;
 
-    /// Clear bit.
-    fn bit_clr(&mut self, n: u32);
+    /// Widening add. Signed.
+    fn add_wide_s(self, other: Self) -> Self::Double {
+        let (lo, hi) = self.widening_add_s(other);
+        Self::concat(lo, hi)
+    }
 
-    /// Set bit.
-    fn bit_set(&mut self, n: u32);
+    /// Widening add.
+    fn add_wide_u(self, other: Self) -> Self::Double {
+        let (lo, hi) = self.widening_add_u(other);
+        Self::concat(lo, hi)
+    }
 
-    /// Returns the number of leading zeros in the binary representation of self.
-    fn clz(&self) -> u32;
+    /// Computes wide + other, with other sign extended.
+    fn add_wv_s(wide: Self::Double, other: Self) -> Self::Double {
+        wide.wrapping_add(other.widen_s())
+    }
 
-    /// Compare. Signed.
-    fn cmp_s(&self, other: &Self) -> core::cmp::Ordering;
+    /// Computes wide + other, with other zero extended.
+    fn add_wv_u(wide: Self::Double, other: Self) -> Self::Double {
+        wide.wrapping_add(other.widen_u())
+    }
 
-    /// Compare.
-    fn cmp_u(&self, other: &Self) -> core::cmp::Ordering;
+    /// Concatenates two integers, lo holding the lower half of the result.
+    fn concat(lo: Self, hi: Self) -> Self::Double {
+        let n = Self::BITS as usize >> 3;
+        let mut mem = [0u8; 256];
+        lo.put(&mut mem[..n]);
+        hi.put(&mut mem[n..n * 2]);
+        Self::Double::get(&mem[..n * 2])
+    }
 
-    /// Returns the number of ones in the binary representation of self.
-    fn cpop(&self) -> u32;
+    /// Widening multiply. Signed.
+    fn mul_wide_s(self, other: Self) -> Self::Double {
+        let (lo, hi) = self.widening_mul_s(other);
+        Self::concat(lo, hi)
+    }
 
-    /// Returns the number of trailing zeros in the binary representation of self.
-    fn ctz(&self) -> u32;
+    /// Widening multiply of a signed self and an unsigned other.
+    fn mul_wide_su(self, other: Self) -> Self::Double {
+        let (lo, hi) = self.widening_mul_su(other);
+        Self::concat(lo, hi)
+    }
 
-    /// Get a native endian integer value from its representation as a byte slice in little endian.
-    fn get(mem: &[u8]) -> Self {
-        unsafe { core::ptr::read(mem.as_ptr() as *const _) }
+    /// Widening multiply.
+    fn mul_wide_u(self, other: Self) -> Self::Double {
+        let (lo, hi) = self.widening_mul_u(other);
+        Self::concat(lo, hi)
     }
 
-    /// Returns the higher part.
-    fn hi(self) -> Self;
+    /// Widening subtract. Signed.
+    fn sub_wide_s(self, other: Self) -> Self::Double {
+        let (lo, hi) = self.widening_sub_s(other);
+        Self::concat(lo, hi)
+    }
 
-    /// Returns true if highest bit is set.
-    fn is_negative(&self) -> bool;
+    /// Widening subtract.
+    fn sub_wide_u(self, other: Self) -> Self::Double {
+        let (lo, hi) = self.widening_sub_u(other);
+        Self::concat(lo, hi)
+    }
 
-    /// Returns true if highest bit is not set.
-    fn is_positive(&self) -> bool;
+    /// Computes wide - other, with other sign extended.
+    fn sub_wv_s(wide: Self::Double, other: Self) -> Self::Double {
+        wide.wrapping_sub(other.widen_s())
+    }
 
-    /// Returns the lower part.
-    fn lo(self) -> Self;
+    /// Computes wide - other, with other zero extended.
+    fn sub_wv_u(wide: Self::Double, other: Self) -> Self::Double {
+        wide.wrapping_sub(other.widen_u())
+    }
 
-    /// Returns the lower part and sign extend it.
-    fn lo_sext(self) -> Self;
+    /// Sign extends into the double width type.
+    fn widen_s(self) -> Self::Double {
+        Self::concat(self, if self.is_negative() { Self::MAX_U } else { Self::MIN_U })
+    }
 
-    /// Calculates self + rhs. Signed.
-    fn overflowing_add_s(self, other: Self) -> (Self, bool);
+    /// Zero extends into the double width type.
+    fn widen_u(self) -> Self::Double {
+        Self::concat(self, Self::MIN_U)
+    }
+}
 
-    /// Calculates self + rhs.
-    fn overflowing_add_u(self, other: Self) -> (Self, bool);
+/// Integers with a type half as wide. Implemented for E16 to E2048.
+pub trait Narrow: Eint {
+    type Half: Widen<Double = Self>;
 
-    /// Calculates self * rhs. Signed.
-    fn overflowing_mul_s(self, other: Self) -> (Self, bool);
+    /// Splits into the lower and the upper half.
+    fn split(self) -> (Self::Half, Self::Half) {
+        let n = Self::BITS as usize >> 4;
+        let mut mem = [0u8; 256];
+        self.put(&mut mem[..n * 2]);
+        (Self::Half::get(&mem[..n]), Self::Half::get(&mem[n..n * 2]))
+    }
+}
 
-    /// Calculates self * rhs.
-    fn overflowing_mul_u(self, other: Self) -> (Self, bool);
+/// The error type returned when a checked integer conversion fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromEintError(());
 
-    /// Calculates self - rhs. Signed.
-    fn overflowing_sub_s(self, other: Self) -> (Self, bool);
+impl core::fmt::Display for TryFromEintError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "out of range integral type conversion attempted")
+    }
+}
 
-    /// Calculates self - rhs.
-    fn overflowing_sub_u(self, other: Self) -> (Self, bool);
+/// How [`layers::EintBase::get_partial`] fills the bytes above the loaded ones.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Extension {
+    /// Fill with zeros.
+    Zero,
+    /// Fill with copies of the highest loaded bit.
+    Sign,
+}
 
-    /// Save the integer as a byte array in little-endian byte order to memory.
-    fn put(&self, mem: &mut [u8]);
+/// An iterator over all bit positions of an integer, yielding the number of set bits below each position.
+///
+/// This struct is created by [`layers::BitOps::iota`].
+#[derive(Clone, Debug)]
+pub struct Iota<T> {
+    data: T,
+    n: u32,
+    r: u32,
+}
 
-    /// Put the lower part integer as a byte array in little-endian byte order to memory.
-    fn put_lo(&self, mem: &mut [u8]);
+impl<T: Eint> Iterator for Iota<T> {
+    type Item = u32;
 
-    /// Saturating integer addition. Computes self + rhs, saturating at the numeric bounds instead of overflowing. Signed.
-    fn saturating_add_s(self, other: Self) -> (Self, bool) {
-        let r = self.wrapping_add(other);
-        if !(self ^ other).is_negative() {
-            if (r ^ self).is_negative() {
-                let r = if self.is_negative() { Self::MIN_S } else { Self::MAX_S };
-                return (r, true);
-            }
+    fn next(&mut self) -> Option<u32> {
+        if self.n >= T::BITS {
+            return None;
+        }
+        let r = self.r;
+        if self.data.bit(self.n) {
+            self.r += 1;
         }
-        (r, false)
+        self.n += 1;
+        Some(r)
     }
 
-    /// Saturating integer addition. Computes self + rhs, saturating at the numeric bounds instead of overflowing.
-    fn saturating_add_u(self, other: Self) -> (Self, bool) {
-        let (r, overflow) = self.overflowing_add_u(other);
-        if overflow {
-            (Self::MAX_U, overflow)
-        } else {
-            (r, overflow)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = (T::BITS - self.n) as usize;
+        (n, Some(n))
+    }
+}
+
+impl<T: Eint> ExactSizeIterator for Iota<T> {}
+
+/// An iterator over the indices of the set bits of an integer.
+///
+/// This struct is created by [`layers::BitOps::iter_ones`].
+#[derive(Clone, Debug)]
+pub struct IterOnes<T> {
+    data: T,
+}
+
+impl<T: Eint> Iterator for IterOnes<T> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let n = self.data.lowest_set_bit()?;
+        self.data.bit_clr(n);
+        Some(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.data.cpop() as usize;
+        (n, Some(n))
+    }
+}
+
+impl<T: Eint> ExactSizeIterator for IterOnes<T> {}
+
+/// An iterator over the lanes of an integer.
+///
+/// This struct is created by [`layers::LaneOps::lanes`].
+#[derive(Clone, Debug)]
+pub struct Lanes<T, L> {
+    data: T,
+    i: usize,
+    n: usize,
+    lane: core::marker::PhantomData<L>,
+}
+
+impl<T: Eint, L: Lane> Iterator for Lanes<T, L> {
+    type Item = L;
+
+    fn next(&mut self) -> Option<L> {
+        if self.i >= self.n {
+            return None;
         }
+        let r = self.data.lane(self.i);
+        self.i += 1;
+        Some(r)
     }
 
-    /// Saturating integer subtraction. Computes self - rhs, saturating at the numeric bounds instead of overflowing. Signed.
-    fn saturating_sub_s(self, other: Self) -> (Self, bool) {
-        let r = self.wrapping_sub(other);
-        if (self ^ other).is_negative() {
-            if (r ^ self).is_negative() {
-                let r = if self.is_negative() { Self::MIN_S } else { Self::MAX_S };
-                return (r, true);
-            }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.n - self.i;
+        (n, Some(n))
+    }
+}
+
+impl<T: Eint, L: Lane> DoubleEndedIterator for Lanes<T, L> {
+    fn next_back(&mut self) -> Option<L> {
+        if self.i >= self.n {
+            return None;
         }
-        (r, false)
+        self.n -= 1;
+        Some(self.data.lane(self.n))
+    }
+}
+
+impl<T: Eint, L: Lane> ExactSizeIterator for Lanes<T, L> {}
+
+/// A copy of the u64 limbs of an integer, least significant limb first. Dereferences to a slice of
+/// [`layers::EintBase::LIMBS`] limbs.
+///
+/// This struct is created by [`layers::EintBase::limbs`].
+#[derive(Clone, Copy, Debug)]
+pub struct LimbBuf {
+    limbs: [u64; 32],
+    len: usize,
+}
+
+impl core::ops::Deref for LimbBuf {
+    type Target = [u64];
+
+    fn deref(&self) -> &[u64] {
+        &self.limbs[..self.len]
     }
+}
+
+/// A mutable view of the u64 limbs of an integer, least significant limb first. Dereferences to a mutable slice of
+/// [`layers::EintBase::LIMBS`] limbs, which are written back to the integer when the view is dropped.
+///
+/// This struct is created by [`layers::EintBase::limbs_mut`].
+pub struct LimbsMut<'a, T> {
+    data: &'a mut T,
+    buf: LimbBuf,
+    store: fn(&mut T, &[u64]),
+}
 
-    /// Saturating integer subtraction. Computes self - rhs, saturating at the numeric bounds instead of overflowing.
-    fn saturating_sub_u(self, other: Self) -> (Self, bool) {
-        if self >= other {
-            (self.wrapping_sub(other), false)
+impl<T> core::ops::Deref for LimbsMut<'_, T> {
+    type Target = [u64];
+
+    fn deref(&self) -> &[u64] {
+        &self.buf
+    }
+}
+
+impl<T> core::ops::DerefMut for LimbsMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut [u64] {
+        &mut self.buf.limbs[..self.buf.len]
+    }
+}
+
+impl<T> Drop for LimbsMut<'_, T> {
+    fn drop(&mut self) {
+        (self.store)(self.data, &self.buf)
+    }
+}
+
+/// Writes the unsigned decimal representation of x to the end of buf and returns it.
+pub(crate) fn write_decimal_u<T: Eint>(x: T, buf: &mut [u8; 640]) -> &str {
+    let (base, width) = if T::BITS >= 64 { (T::from(10_000_000_000_000_000_000u64), 19) } else { (T::from(10u8), 1) };
+    let mut i = buf.len();
+    let mut x = x;
+    loop {
+        let q = x.wrapping_div_u(base);
+        let mut r = x.wrapping_sub(q.wrapping_mul(base)).u64();
+        x = q;
+        for _ in 0..width {
+            i -= 1;
+            buf[i] = b'0' + (r % 10) as u8;
+            r /= 10;
+            if r == 0 && x == T::MIN_U {
+                break;
+            }
+        }
+        if x == T::MIN_U {
+            break;
+        }
+    }
+    core::str::from_utf8(&buf[i..]).unwrap()
+}
+
+/// An integer with two's complement signed semantics.
+///
+/// Signed<T> has the same representation as T, but Ord, Div, Rem and Shr are signed, and Display prints a signed
+/// decimal number. Convert between the two with From or through the public field. Primitive signed integers convert
+/// with From when they are no wider than T, and with TryFrom, which fails if the value is out of range, otherwise.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Signed<T: Eint>(pub T);
+
+pub type I8 = Signed<E8>;
+pub type I16 = Signed<E16>;
+pub type I32 = Signed<E32>;
+pub type I64 = Signed<E64>;
+pub type I128 = Signed<E128>;
+pub type I256 = Signed<E256>;
+pub type I512 = Signed<E512>;
+pub type I1024 = Signed<E1024>;
+pub type I2048 = Signed<E2048>;
+
+impl<T: Eint> Signed<T> {
+    pub const MAX: Self = Self(T::MAX_S);
+    pub const MIN: Self = Self(T::MIN_S);
+    pub const ONE: Self = Self(T::ONE);
+    pub const ZERO: Self = Self(T::ZERO);
+
+    /// Returns the absolute value as an unsigned integer. The absolute value of MIN is representable.
+    pub fn unsigned_abs(self) -> T {
+        if self.0.is_negative() {
+            -self.0
         } else {
-            (Self::MIN_U, true)
+            self.0
         }
     }
+}
 
-    /// Sign extended.
-    fn sext(self, other: u32) -> Self {
-        self.wrapping_shl(Self::BITS - other - 1).wrapping_sra(Self::BITS - other - 1)
+impl<T: Eint> From<T> for Signed<T> {
+    fn from(x: T) -> Self {
+        Self(x)
     }
+}
 
-    /// Returns the lower 8 bits.
-    fn u8(self) -> u8;
+macro_rules! construct_signed_from {
+    ($name:ident; $($small:ty),*; $($large:ty),*) => {
+        $(
+            impl From<$small> for Signed<$name> {
+                fn from(small: $small) -> Self {
+                    Self($name::from(small))
+                }
+            }
+        )*
+        $(
+            impl TryFrom<$large> for Signed<$name> {
+                type Error = TryFromEintError;
 
-    /// Returns the lower 16 bits.
-    fn u16(self) -> u16;
+                fn try_from(large: $large) -> Result<Self, Self::Error> {
+                    let shamt = <$large>::BITS - <$name as Eint>::BITS;
+                    if (large << shamt) >> shamt == large {
+                        Ok(Self($name::from(large)))
+                    } else {
+                        Err(TryFromEintError(()))
+                    }
+                }
+            }
+        )*
+    };
+}
 
-    /// Returns the lower 32 bits.
-    fn u32(self) -> u32;
+construct_signed_from!(E8; i8; i16, i32, i64, i128);
+construct_signed_from!(E16; i8, i16; i32, i64, i128);
+construct_signed_from!(E32; i8, i16, i32; i64, i128);
+construct_signed_from!(E64; i8, i16, i32, i64; i128);
+construct_signed_from!(E128; i8, i16, i32, i64, i128;);
+construct_signed_from!(E256; i8, i16, i32, i64, i128;);
+construct_signed_from!(E512; i8, i16, i32, i64, i128;);
+construct_signed_from!(E1024; i8, i16, i32, i64, i128;);
+construct_signed_from!(E2048; i8, i16, i32, i64, i128;);
+
+macro_rules! construct_wrapper_op {
+    ($wrapper:ident, $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $imp:expr) => {
+        impl<T: Eint> core::ops::$op for $wrapper<T> {
+            type Output = Self;
+            fn $fn(self, other: Self) -> Self::Output {
+                Self($imp(self.0, other.0))
+            }
+        }
 
-    /// Returns the lower 64 bits.
-    fn u64(self) -> u64;
+        impl<T: Eint> core::ops::$op_assign for $wrapper<T> {
+            fn $fn_assign(&mut self, other: Self) {
+                self.0 = $imp(self.0, other.0)
+            }
+        }
+    };
+}
 
-    /// Widening add. Signed.
-    /// (lo, hi) = x + y with the product bits' upper half returned in hi and the lower half returned in lo.
-    fn widening_add_s(self, other: Self) -> (Self, Self) {
-        let hi_0 = if self.is_negative() { Self::MAX_U } else { Self::MIN_U };
-        let hi_1 = if other.is_negative() { Self::MAX_U } else { Self::MIN_U };
-        let (lo, carry) = self.overflowing_add_u(other);
-        let hi = hi_0.wrapping_add(hi_1).wrapping_add(Self::from(carry));
-        (lo, hi)
+construct_wrapper_op!(Signed, Add, add, AddAssign, add_assign, T::wrapping_add);
+construct_wrapper_op!(Signed, BitAnd, bitand, BitAndAssign, bitand_assign, core::ops::BitAnd::bitand);
+construct_wrapper_op!(Signed, BitOr, bitor, BitOrAssign, bitor_assign, core::ops::BitOr::bitor);
+construct_wrapper_op!(Signed, BitXor, bitxor, BitXorAssign, bitxor_assign, core::ops::BitXor::bitxor);
+construct_wrapper_op!(Signed, Div, div, DivAssign, div_assign, T::wrapping_div_s);
+construct_wrapper_op!(Signed, Mul, mul, MulAssign, mul_assign, T::wrapping_mul);
+construct_wrapper_op!(Signed, Rem, rem, RemAssign, rem_assign, T::wrapping_rem_s);
+construct_wrapper_op!(Signed, Sub, sub, SubAssign, sub_assign, T::wrapping_sub);
+
+impl<T: Eint> core::cmp::Ord for Signed<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp_s(&other.0)
     }
+}
 
-    /// Widening add.
-    /// (lo, hi) = x + y with the product bits' upper half returned in hi and the lower half returned in lo.
-    fn widening_add_u(self, other: Self) -> (Self, Self) {
-        let (lo, carry) = self.overflowing_add_u(other);
-        (lo, Self::from(carry))
+impl<T: Eint> core::cmp::PartialOrd for Signed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
     }
+}
 
-    /// Widening multiple. Signed.
-    ///
-    /// (lo, hi) = x * y with the product bits' upper half returned in hi and the lower half returned in lo.
-    /// Inspired by https://sqlite.in/?qa=668884/c-32-bit-signed-integer-multiplication-without-using-64-bit-data-type
-    fn widening_mul_s(self, other: Self) -> (Self, Self) {
-        let (lo, hi) = self.widening_mul_u(other);
-        let hi = hi
-            - if self.is_negative() { other } else { Self::MIN_U }
-            - if other.is_negative() { self } else { Self::MIN_U };
-        (lo, hi)
+impl<T: Eint> core::fmt::Debug for Signed<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T: Eint> core::fmt::Display for Signed<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0u8; 640];
+        f.pad_integral(!self.0.is_negative(), "", write_decimal_u(self.unsigned_abs(), &mut buf))
+    }
+}
+
+impl<T: Eint> core::fmt::LowerHex for Signed<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl<T: Eint> core::ops::Neg for Signed<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl<T: Eint> core::ops::Not for Signed<T> {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+impl<T: Eint> core::ops::Shl<u32> for Signed<T> {
+    type Output = Self;
+    fn shl(self, other: u32) -> Self::Output {
+        Self(self.0.wrapping_shl(other))
+    }
+}
+
+impl<T: Eint> core::ops::Shr<u32> for Signed<T> {
+    type Output = Self;
+    fn shr(self, other: u32) -> Self::Output {
+        Self(self.0.wrapping_sra(other))
+    }
+}
+
+/// An unsigned integer whose operators saturate at MIN_U and MAX_U instead of wrapping around.
+///
+/// Division by zero yields MAX_U. A left shift saturates to MAX_U when it would shift out a set bit, a right shift by
+/// BITS or more yields zero.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Saturating<T: Eint>(pub T);
+
+impl<T: Eint> From<T> for Saturating<T> {
+    fn from(x: T) -> Self {
+        Self(x)
+    }
+}
+
+construct_wrapper_op!(Saturating, Add, add, AddAssign, add_assign, |a: T, b| a.saturating_add_u(b).0);
+construct_wrapper_op!(Saturating, Div, div, DivAssign, div_assign, T::wrapping_div_u);
+construct_wrapper_op!(Saturating, Mul, mul, MulAssign, mul_assign, |a: T, b| match a.overflowing_mul_u(b) {
+    (r, false) => r,
+    (_, true) => T::MAX_U,
+});
+construct_wrapper_op!(Saturating, Sub, sub, SubAssign, sub_assign, |a: T, b| a.saturating_sub_u(b).0);
+
+impl<T: Eint> core::fmt::Debug for Saturating<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.0, f)
     }
+}
 
-    /// Widening signed and unsigned integer multiply.
-    /// (lo, hi) = x * y with the product bits' upper half returned in hi and the lower half returned in lo.
-    fn widening_mul_su(self, other: Self) -> (Self, Self) {
-        if !other.is_negative() {
-            self.widening_mul_s(other)
+impl<T: Eint> core::fmt::Display for Saturating<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: Eint> core::ops::Shl<u32> for Saturating<T> {
+    type Output = Self;
+    fn shl(self, other: u32) -> Self::Output {
+        if self.0 == T::MIN_U {
+            self
+        } else if other >= T::BITS || self.0.clz() < other {
+            Self(T::MAX_U)
         } else {
-            let (lo, hi) = self.widening_mul_s(other);
-            let hi = hi + self;
-            (lo, hi)
+            Self(self.0.wrapping_shl(other))
         }
     }
+}
 
-    /// Widening multiple.
-    /// (lo, hi) = x * y with the product bits' upper half returned in hi and the lower half returned in lo.
-    fn widening_mul_u(self, other: Self) -> (Self, Self) {
-        self._widening_mul_u(other)
+impl<T: Eint> core::ops::ShlAssign<u32> for Saturating<T> {
+    fn shl_assign(&mut self, other: u32) {
+        *self = core::ops::Shl::shl(*self, other)
     }
+}
 
-    /// Widening substract. Signed.
-    /// (lo, hi) = x - y with the product bits' upper half returned in hi and the lower half returned in lo.
-    fn widening_sub_s(self, other: Self) -> (Self, Self) {
-        let hi_0 = if self.is_negative() { Self::MAX_U } else { Self::MIN_U };
-        let hi_1 = if other.is_negative() { Self::MAX_U } else { Self::MIN_U };
-        let (lo, borrow) = self.overflowing_sub_u(other);
-        let hi = hi_0.wrapping_sub(hi_1).wrapping_sub(Self::from(borrow));
-        (lo, hi)
+impl<T: Eint> core::ops::Shr<u32> for Saturating<T> {
+    type Output = Self;
+    fn shr(self, other: u32) -> Self::Output {
+        if other >= T::BITS {
+            Self(T::MIN_U)
+        } else {
+            Self(self.0.wrapping_shr(other))
+        }
+    }
+}
+
+impl<T: Eint> core::ops::ShrAssign<u32> for Saturating<T> {
+    fn shr_assign(&mut self, other: u32) {
+        *self = core::ops::Shr::shr(*self, other)
     }
+}
+
+/// An unsigned integer whose operators detect overflow. The first overflow poisons the value, and every result
+/// computed from a poisoned value is poisoned too.
+///
+/// Overflow is an unsigned carry or borrow, a product that does not fit, a division by zero, or a shift by BITS or more.
+/// All poisoned values are equal to each other and never equal to a value that is not poisoned.
+#[derive(Clone, Copy, Default, Eq)]
+pub struct Checked<T: Eint> {
+    value: T,
+    poisoned: bool,
+}
 
-    /// Widening substract.
-    /// (lo, hi) = x - y with the product bits' upper half returned in hi and the lower half returned in lo.
-    fn widening_sub_u(self, other: Self) -> (Self, Self) {
-        let (lo, borrow) = self.overflowing_sub_u(other);
-        (lo, if borrow { Self::MAX_U } else { Self::MIN_U })
+impl<T: Eint> Checked<T> {
+    /// Wraps a value. The result is not poisoned.
+    pub fn new(value: T) -> Self {
+        Self { value, poisoned: false }
     }
 
-    /// Wrapping (modular) addition. Computes self + rhs, wrapping around at the boundary of the type.
-    fn wrapping_add(self, other: Self) -> Self;
+    /// Returns the value, or None if it is poisoned.
+    pub fn get(self) -> Option<T> {
+        if self.poisoned {
+            None
+        } else {
+            Some(self.value)
+        }
+    }
 
-    /// Wrapping (modular) division. Computes self / rhs.
-    /// 1) x / 0 = MAX_U
-    fn wrapping_div_u(self, other: Self) -> Self;
+    /// Returns true if an overflow happened while computing the value.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+}
+
+impl<T: Eint> From<T> for Checked<T> {
+    fn from(x: T) -> Self {
+        Self::new(x)
+    }
+}
+
+macro_rules! construct_checked_op {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $rhs:ty, $imp:expr) => {
+        impl<T: Eint> core::ops::$op<$rhs> for Checked<T> {
+            type Output = Self;
+            fn $fn(self, other: $rhs) -> Self::Output {
+                let (value, poisoned) = $imp(self.value, other);
+                Self { value, poisoned: self.poisoned || poisoned }
+            }
+        }
 
-    /// Wrapping (modular) division. Signed.
-    /// 1) x / 0 = -1.
-    /// 2) MIN_S / -1 = MIN_S
-    fn wrapping_div_s(self, other: Self) -> Self;
+        impl<T: Eint> core::ops::$op_assign<$rhs> for Checked<T> {
+            fn $fn_assign(&mut self, other: $rhs) {
+                *self = core::ops::$op::$fn(*self, other)
+            }
+        }
+    };
+}
 
-    /// Wrapping (modular) multiplication. Computes self * rhs, wrapping around at the boundary of the type.
-    fn wrapping_mul(self, other: Self) -> Self;
+construct_checked_op!(Add, add, AddAssign, add_assign, Self, |a: T, b: Self| {
+    let (r, o) = a.overflowing_add_u(b.value);
+    (r, o || b.poisoned)
+});
+construct_checked_op!(Div, div, DivAssign, div_assign, Self, |a: T, b: Self| {
+    (a.wrapping_div_u(b.value), b.value == T::MIN_U || b.poisoned)
+});
+construct_checked_op!(Mul, mul, MulAssign, mul_assign, Self, |a: T, b: Self| {
+    let (r, o) = a.overflowing_mul_u(b.value);
+    (r, o || b.poisoned)
+});
+construct_checked_op!(Shl, shl, ShlAssign, shl_assign, u32, |a: T, b| (a.wrapping_shl(b), b >= T::BITS));
+construct_checked_op!(Shr, shr, ShrAssign, shr_assign, u32, |a: T, b| (a.wrapping_shr(b), b >= T::BITS));
+construct_checked_op!(Sub, sub, SubAssign, sub_assign, Self, |a: T, b: Self| {
+    let (r, o) = a.overflowing_sub_u(b.value);
+    (r, o || b.poisoned)
+});
+
+impl<T: Eint> PartialEq for Checked<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.poisoned, other.poisoned) {
+            (false, false) => self.value == other.value,
+            (a, b) => a == b,
+        }
+    }
+}
 
-    /// Wrapping (modular) remainder. Signed.
-    /// 1) x % 0 = x
-    /// 2) MIN_S % -1 = 0
-    fn wrapping_rem_s(self, other: Self) -> Self;
+impl<T: Eint> core::fmt::Debug for Checked<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.poisoned {
+            write!(f, "Checked(poisoned)")
+        } else {
+            write!(f, "Checked({:?})", self.value)
+        }
+    }
+}
 
-    /// Wrapping (modular) remainder.
-    /// 1) x % 0 = x
-    fn wrapping_rem_u(self, other: Self) -> Self;
+macro_rules! construct_eint_ref_op {
+    ($name:ident, $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        impl core::ops::$op<&$name> for $name {
+            type Output = $name;
+            fn $fn(self, other: &$name) -> Self::Output {
+                core::ops::$op::$fn(self, *other)
+            }
+        }
 
-    /// Panic-free bitwise shift-left; yields self << mask(rhs), where mask removes any high-order bits of rhs that
-    /// would cause the shift to exceed the bitwidth of the type.
-    fn wrapping_shl(self, other: u32) -> Self;
+        impl core::ops::$op<$name> for &$name {
+            type Output = $name;
+            fn $fn(self, other: $name) -> Self::Output {
+                core::ops::$op::$fn(*self, other)
+            }
+        }
 
-    /// Panic-free bitwise shift-right; yields self >> mask(rhs), where mask removes any high-order bits of rhs that
-    /// would cause the shift to exceed the bitwidth of the type.
-    fn wrapping_shr(self, other: u32) -> Self;
+        impl core::ops::$op<&$name> for &$name {
+            type Output = $name;
+            fn $fn(self, other: &$name) -> Self::Output {
+                core::ops::$op::$fn(*self, *other)
+            }
+        }
 
-    /// Panic-free bitwise sign shift-right.
-    fn wrapping_sra(self, other: u32) -> Self;
+        impl core::ops::$op_assign<&$name> for $name {
+            fn $fn_assign(&mut self, other: &$name) {
+                core::ops::$op_assign::$fn_assign(self, *other)
+            }
+        }
+    };
+}
 
-    /// Wrapping (modular) subtraction. Computes self - rhs, wrapping around at the boundary of the type.
-    fn wrapping_sub(self, other: Self) -> Self;
+macro_rules! construct_eint_prim_op {
+    ($name:ident, $prim:ty, $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        impl core::ops::$op<$prim> for $name {
+            type Output = $name;
+            fn $fn(self, other: $prim) -> Self::Output {
+                core::ops::$op::$fn(self, Self::from(other))
+            }
+        }
 
-    /// Zero extended.
-    fn zext(self, other: u32) -> Self {
-        self.wrapping_shl(Self::BITS - other - 1).wrapping_shr(Self::BITS - other - 1)
-    }
+        impl core::ops::$op_assign<$prim> for $name {
+            fn $fn_assign(&mut self, other: $prim) {
+                core::ops::$op_assign::$fn_assign(self, Self::from(other))
+            }
+        }
+    };
+}
+
+macro_rules! construct_eint_prim {
+    ($name:ident, $prim:ty) => {
+        construct_eint_prim_op!($name, $prim, Add, add, AddAssign, add_assign);
+        construct_eint_prim_op!($name, $prim, BitAnd, bitand, BitAndAssign, bitand_assign);
+        construct_eint_prim_op!($name, $prim, BitOr, bitor, BitOrAssign, bitor_assign);
+        construct_eint_prim_op!($name, $prim, BitXor, bitxor, BitXorAssign, bitxor_assign);
+        construct_eint_prim_op!($name, $prim, Div, div, DivAssign, div_assign);
+        construct_eint_prim_op!($name, $prim, Mul, mul, MulAssign, mul_assign);
+        construct_eint_prim_op!($name, $prim, Rem, rem, RemAssign, rem_assign);
+        construct_eint_prim_op!($name, $prim, Sub, sub, SubAssign, sub_assign);
+    };
+}
+
+macro_rules! construct_eint_prim_cmp {
+    ($name:ident, $prim:ty) => {
+        impl core::cmp::PartialEq<$prim> for $name {
+            fn eq(&self, other: &$prim) -> bool {
+                self.partial_cmp(other) == Some(core::cmp::Ordering::Equal)
+            }
+        }
+
+        impl core::cmp::PartialEq<$name> for $prim {
+            fn eq(&self, other: &$name) -> bool {
+                other == self
+            }
+        }
+
+        impl core::cmp::PartialOrd<$prim> for $name {
+            fn partial_cmp(&self, other: &$prim) -> Option<core::cmp::Ordering> {
+                if (*other as u128).checked_shr(Self::BITS).unwrap_or(0) != 0 {
+                    Some(core::cmp::Ordering::Less)
+                } else {
+                    Some(self.cmp_u(&Self::from(*other)))
+                }
+            }
+        }
+
+        impl core::cmp::PartialOrd<$name> for $prim {
+            fn partial_cmp(&self, other: &$name) -> Option<core::cmp::Ordering> {
+                other.partial_cmp(self).map(core::cmp::Ordering::reverse)
+            }
+        }
+    };
+}
+
+/// Operators shared by all integer types: operands by reference, mixed operands with primitive integers, shifts by
+/// another Eint and iterator folds. Primitive operands are converted with From, so signed primitives are sign
+/// extended; comparisons with primitives are only provided for unsigned primitives and compare values exactly.
+macro_rules! construct_eint_ops {
+    ($name:ident) => {
+        construct_eint_ref_op!($name, Add, add, AddAssign, add_assign);
+        construct_eint_ref_op!($name, BitAnd, bitand, BitAndAssign, bitand_assign);
+        construct_eint_ref_op!($name, BitOr, bitor, BitOrAssign, bitor_assign);
+        construct_eint_ref_op!($name, BitXor, bitxor, BitXorAssign, bitxor_assign);
+        construct_eint_ref_op!($name, Div, div, DivAssign, div_assign);
+        construct_eint_ref_op!($name, Mul, mul, MulAssign, mul_assign);
+        construct_eint_ref_op!($name, Rem, rem, RemAssign, rem_assign);
+        construct_eint_ref_op!($name, Sub, sub, SubAssign, sub_assign);
+        construct_eint_prim!($name, i8);
+        construct_eint_prim!($name, i16);
+        construct_eint_prim!($name, i32);
+        construct_eint_prim!($name, i64);
+        construct_eint_prim!($name, i128);
+        construct_eint_prim!($name, u8);
+        construct_eint_prim!($name, u16);
+        construct_eint_prim!($name, u32);
+        construct_eint_prim!($name, u64);
+        construct_eint_prim!($name, u128);
+        construct_eint_prim_cmp!($name, u8);
+        construct_eint_prim_cmp!($name, u16);
+        construct_eint_prim_cmp!($name, u32);
+        construct_eint_prim_cmp!($name, u64);
+        construct_eint_prim_cmp!($name, u128);
+
+        impl core::ops::Neg for &$name {
+            type Output = $name;
+            fn neg(self) -> Self::Output {
+                -*self
+            }
+        }
+
+        impl core::ops::Not for &$name {
+            type Output = $name;
+            fn not(self) -> Self::Output {
+                !*self
+            }
+        }
+
+        /// Shifts by the low bits of other, masked to the bit width of the type.
+        impl<T: Eint> core::ops::Shl<T> for $name {
+            type Output = Self;
+            fn shl(self, other: T) -> Self::Output {
+                self.wrapping_shl(other.u32())
+            }
+        }
+
+        /// Shifts by the low bits of other, masked to the bit width of the type.
+        impl<T: Eint> core::ops::Shr<T> for $name {
+            type Output = Self;
+            fn shr(self, other: T) -> Self::Output {
+                self.wrapping_shr(other.u32())
+            }
+        }
+
+        impl core::iter::Product for $name {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |a, b| a.wrapping_mul(b))
+            }
+        }
+
+        impl<'a> core::iter::Product<&'a $name> for $name {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |a, b| a.wrapping_mul(*b))
+            }
+        }
+
+        impl core::iter::Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |a, b| a.wrapping_add(b))
+            }
+        }
+
+        impl<'a> core::iter::Sum<&'a $name> for $name {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |a, b| a.wrapping_add(*b))
+            }
+        }
+    };
+}
+
+macro_rules! construct_eint_shift {
+    ($name:ident, $rhs:ty) => {
+        impl core::ops::Shl<$rhs> for $name {
+            type Output = Self;
+            fn shl(self, other: $rhs) -> Self::Output {
+                self.wrapping_shl(other as u32)
+            }
+        }
+
+        impl core::ops::Shr<$rhs> for $name {
+            type Output = Self;
+            fn shr(self, other: $rhs) -> Self::Output {
+                self.wrapping_shr(other as u32)
+            }
+        }
+    };
 }
 
 macro_rules! construct_eint_wrap_from_uint {
@@ -382,8 +1220,16 @@ macro_rules! construct_eint_wrap_from_uint {
 macro_rules! construct_eint_wrap {
     ($name:ident, $uint:ty, $sint:ty, $fstring:expr) => {
         #[derive(Copy, Clone, Default, PartialEq, Eq)]
+        #[cfg_attr(feature = "zerocopy", derive(::zerocopy::AsBytes, ::zerocopy::FromBytes, ::zerocopy::FromZeroes))]
+        #[repr(transparent)]
         pub struct $name(pub $uint);
 
+        impl core::convert::From<Signed<$name>> for $name {
+            fn from(x: Signed<$name>) -> Self {
+                x.0
+            }
+        }
+
         construct_eint_wrap_from_uint!($name, $uint, bool);
         construct_eint_wrap_from_uint!($name, $uint, i8);
         construct_eint_wrap_from_uint!($name, $uint, i16);
@@ -395,6 +1241,18 @@ macro_rules! construct_eint_wrap {
         construct_eint_wrap_from_uint!($name, $uint, u32);
         construct_eint_wrap_from_uint!($name, $uint, u64);
         construct_eint_wrap_from_uint!($name, $uint, u128);
+        construct_eint_ops!($name);
+        construct_eint_shift!($name, i8);
+        construct_eint_shift!($name, i16);
+        construct_eint_shift!($name, i32);
+        construct_eint_shift!($name, i64);
+        construct_eint_shift!($name, i128);
+        construct_eint_shift!($name, isize);
+        construct_eint_shift!($name, u8);
+        construct_eint_shift!($name, u16);
+        construct_eint_shift!($name, u64);
+        construct_eint_shift!($name, u128);
+        construct_eint_shift!($name, usize);
 
         impl core::cmp::Ord for $name {
             fn cmp(&self, other: &Self) -> core::cmp::Ordering {
@@ -566,15 +1424,72 @@ macro_rules! construct_eint_wrap {
             }
         }
 
-        impl Eint for $name {
+        impl private::Sealed for $name {}
+
+        impl layers::EintBase for $name {
             const BITS: u32 = <$uint>::MIN.leading_zeros();
+
             const MIN_U: Self = Self(0);
+
             const MAX_U: Self = Self(<$uint>::MAX);
+
             const MIN_S: Self = Self(<$sint>::MIN as $uint);
+
             const MAX_S: Self = Self(<$sint>::MAX as $uint);
+
             const ONE: Self = Self(1);
+
             const ZERO: Self = Self(0);
 
+            fn get(mem: &[u8]) -> Self {
+                let mut buf = [0u8; <Self as layers::EintBase>::BITS as usize >> 3];
+                buf.copy_from_slice(&mem);
+                Self(<$uint>::from_le_bytes(buf))
+            }
+
+            fn put(&self, mem: &mut [u8]) {
+                let buf = self.0.to_le_bytes();
+                mem.copy_from_slice(&buf);
+            }
+
+            fn limb(&self, i: usize) -> u64 {
+                assert!(i < <Self as layers::EintBase>::LIMBS, "limb index out of bounds");
+                (self.0 as u128 >> (i << 6)) as u64
+            }
+
+            fn put_lo(&self, mem: &mut [u8]) {
+                let buf = self.0.to_le_bytes();
+                if <Self as layers::EintBase>::BITS == 8 {
+                    mem[0] = buf[0] & 0x0f
+                } else {
+                    mem.copy_from_slice(&buf[0..buf.len() >> 1]);
+                }
+            }
+
+            fn set_limb(&mut self, i: usize, v: u64) {
+                assert!(i < <Self as layers::EintBase>::LIMBS, "limb index out of bounds");
+                let m = (u64::MAX as u128) << (i << 6);
+                self.0 = (self.0 as u128 & !m | (v as u128) << (i << 6)) as $uint;
+            }
+
+            fn u8(self) -> u8 {
+                self.0 as u8
+            }
+
+            fn u16(self) -> u16 {
+                self.0 as u16
+            }
+
+            fn u32(self) -> u32 {
+                self.0 as u32
+            }
+
+            fn u64(self) -> u64 {
+                self.0 as u64
+            }
+        }
+
+        impl layers::BitOps for $name {
             fn bit(&self, n: u32) -> bool {
                 self.0.wrapping_shr(n) & 1 != 0
             }
@@ -587,16 +1502,12 @@ macro_rules! construct_eint_wrap {
                 self.0 |= <$name>::ONE.0.wrapping_shl(n)
             }
 
-            fn clz(&self) -> u32 {
-                self.0.leading_zeros()
-            }
-
-            fn cmp_s(&self, other: &Self) -> core::cmp::Ordering {
-                (self.0 as $sint).cmp(&(other.0 as $sint))
+            fn brev8(self) -> Self {
+                Self(self.0.reverse_bits().swap_bytes())
             }
 
-            fn cmp_u(&self, other: &Self) -> core::cmp::Ordering {
-                self.0.cmp(&other.0)
+            fn clz(&self) -> u32 {
+                self.0.leading_zeros()
             }
 
             fn cpop(&self) -> u32 {
@@ -607,35 +1518,92 @@ macro_rules! construct_eint_wrap {
                 self.0.trailing_zeros()
             }
 
-            fn get(mem: &[u8]) -> Self {
-                let mut buf = [0u8; Self::BITS as usize >> 3];
-                buf.copy_from_slice(&mem);
-                Self(<$uint>::from_le_bytes(buf))
+            fn leading_ones(&self) -> u32 {
+                self.0.leading_ones()
             }
 
-            fn hi(self) -> Self {
-                self >> (Self::BITS >> 1)
+            fn pdep(self, mask: Self) -> Self {
+                let mut r: $uint = 0;
+                let mut m = mask.0;
+                let mut k = 0;
+                while m != 0 {
+                    r |= ((self.0 >> k) & 1) << m.trailing_zeros();
+                    m &= m - 1;
+                    k += 1;
+                }
+                Self(r)
             }
 
-            fn is_negative(&self) -> bool {
-                (self.0 as $sint).is_negative()
+            fn pext(self, mask: Self) -> Self {
+                let mut r: $uint = 0;
+                let mut m = mask.0;
+                let mut k = 0;
+                while m != 0 {
+                    r |= ((self.0 >> m.trailing_zeros()) & 1) << k;
+                    m &= m - 1;
+                    k += 1;
+                }
+                Self(r)
             }
 
-            fn is_positive(&self) -> bool {
-                (self.0 as $sint).is_positive()
+            fn reverse_bits(self) -> Self {
+                Self(self.0.reverse_bits())
             }
 
-            fn lo(self) -> Self {
-                self & (Self::MAX_U >> (Self::BITS >> 1))
+            fn rotate_left(self, other: u32) -> Self {
+                Self(self.0.rotate_left(other))
             }
 
-            fn lo_sext(self) -> Self {
-                self.sext((Self::BITS >> 1) - 1)
+            fn rotate_right(self, other: u32) -> Self {
+                Self(self.0.rotate_right(other))
             }
 
-            fn overflowing_add_s(self, other: Self) -> (Self, bool) {
-                let (r, carry) = (self.0 as $sint).overflowing_add(other.0 as $sint);
-                (Self(r as $uint), carry)
+            fn swap_bytes(self) -> Self {
+                Self(self.0.swap_bytes())
+            }
+
+            fn trailing_ones(&self) -> u32 {
+                self.0.trailing_ones()
+            }
+
+            fn widening_clmul(self, other: Self) -> (Self, Self) {
+                let x = [self.0 as u128 as u64, (self.0 as u128 >> 64) as u64];
+                let y = [other.0 as u128 as u64, (other.0 as u128 >> 64) as u64];
+                let mut r = [0u64; 4];
+                clmul_limbs(&x, &y, &mut r);
+                let lo = r[0] as u128 | (r[1] as u128) << 64;
+                let hi = r[2] as u128 | (r[3] as u128) << 64;
+                (Self(lo as $uint), Self((lo.checked_shr(Self::BITS).unwrap_or(0) | hi) as $uint))
+            }
+
+            fn wrapping_shl(self, other: u32) -> Self {
+                Self(self.0.wrapping_shl(other))
+            }
+
+            fn wrapping_shr(self, other: u32) -> Self {
+                Self(self.0.wrapping_shr(other))
+            }
+
+            fn wrapping_sra(self, other: u32) -> Self {
+                Self((self.0 as $sint).wrapping_shr(other) as $uint)
+            }
+        }
+
+        impl layers::UnsignedArith for $name {
+            fn borrowing_sub(self, other: Self, borrow: bool) -> (Self, bool) {
+                let (r0, borrow0) = self.0.overflowing_sub(other.0);
+                let (r1, borrow1) = r0.overflowing_sub(borrow as $uint);
+                (Self(r1), borrow0 | borrow1)
+            }
+
+            fn carrying_add(self, other: Self, carry: bool) -> (Self, bool) {
+                let (r0, carry0) = self.0.overflowing_add(other.0);
+                let (r1, carry1) = r0.overflowing_add(carry as $uint);
+                (Self(r1), carry0 | carry1)
+            }
+
+            fn cmp_u(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
             }
 
             fn overflowing_add_u(self, other: Self) -> (Self, bool) {
@@ -643,58 +1611,71 @@ macro_rules! construct_eint_wrap {
                 (Self(r), carry)
             }
 
-            fn overflowing_mul_s(self, other: Self) -> (Self, bool) {
-                let (r, carry) = (self.0 as $sint).overflowing_mul(other.0 as $sint);
-                (Self(r as $uint), carry)
-            }
-
             fn overflowing_mul_u(self, other: Self) -> (Self, bool) {
                 let (r, carry) = self.0.overflowing_mul(other.0);
                 (Self(r), carry)
             }
 
-            fn overflowing_sub_s(self, other: Self) -> (Self, bool) {
-                let (r, borrow) = (self.0 as $sint).overflowing_sub(other.0 as $sint);
-                (Self(r as $uint), borrow)
-            }
-
             fn overflowing_sub_u(self, other: Self) -> (Self, bool) {
                 let (r, borrow) = self.0.overflowing_sub(other.0);
                 (Self(r), borrow)
             }
 
-            fn put(&self, mem: &mut [u8]) {
-                let buf = self.0.to_le_bytes();
-                mem.copy_from_slice(&buf);
+            fn wrapping_add(self, other: Self) -> Self {
+                Self(self.0.wrapping_add(other.0))
             }
 
-            fn put_lo(&self, mem: &mut [u8]) {
-                let buf = self.0.to_le_bytes();
-                if Self::BITS == 8 {
-                    mem[0] = buf[0] & 0x0f
+            fn wrapping_div_u(self, other: Self) -> Self {
+                if other.0 == 0 {
+                    Self::MAX_U
                 } else {
-                    mem.copy_from_slice(&buf[0..buf.len() >> 1]);
+                    Self(self.0.wrapping_div(other.0))
                 }
             }
 
-            fn u8(self) -> u8 {
-                self.0 as u8
+            fn wrapping_mul(self, other: Self) -> Self {
+                Self(self.0.wrapping_mul(other.0))
+            }
+
+            fn wrapping_rem_u(self, other: Self) -> Self {
+                if other.0 == 0 {
+                    self
+                } else {
+                    Self(self.0.wrapping_rem(other.0))
+                }
+            }
+
+            fn wrapping_sub(self, other: Self) -> Self {
+                Self(self.0.wrapping_sub(other.0))
+            }
+        }
+
+        impl layers::SignedArith for $name {
+            fn cmp_s(&self, other: &Self) -> core::cmp::Ordering {
+                (self.0 as $sint).cmp(&(other.0 as $sint))
+            }
+
+            fn is_negative(&self) -> bool {
+                (self.0 as $sint).is_negative()
             }
 
-            fn u16(self) -> u16 {
-                self.0 as u16
+            fn is_positive(&self) -> bool {
+                (self.0 as $sint).is_positive()
             }
 
-            fn u32(self) -> u32 {
-                self.0 as u32
+            fn overflowing_add_s(self, other: Self) -> (Self, bool) {
+                let (r, carry) = (self.0 as $sint).overflowing_add(other.0 as $sint);
+                (Self(r as $uint), carry)
             }
 
-            fn u64(self) -> u64 {
-                self.0 as u64
+            fn overflowing_mul_s(self, other: Self) -> (Self, bool) {
+                let (r, carry) = (self.0 as $sint).overflowing_mul(other.0 as $sint);
+                (Self(r as $uint), carry)
             }
 
-            fn wrapping_add(self, other: Self) -> Self {
-                Self(self.0.wrapping_add(other.0))
+            fn overflowing_sub_s(self, other: Self) -> (Self, bool) {
+                let (r, borrow) = (self.0 as $sint).overflowing_sub(other.0 as $sint);
+                (Self(r as $uint), borrow)
             }
 
             fn wrapping_div_s(self, other: Self) -> Self {
@@ -707,18 +1688,6 @@ macro_rules! construct_eint_wrap {
                 }
             }
 
-            fn wrapping_div_u(self, other: Self) -> Self {
-                if other.0 == 0 {
-                    Self::MAX_U
-                } else {
-                    Self(self.0.wrapping_div(other.0))
-                }
-            }
-
-            fn wrapping_mul(self, other: Self) -> Self {
-                Self(self.0.wrapping_mul(other.0))
-            }
-
             fn wrapping_rem_s(self, other: Self) -> Self {
                 if other.0 == 0 {
                     self
@@ -728,29 +1697,23 @@ macro_rules! construct_eint_wrap {
                     Self((self.0 as $sint).wrapping_rem(other.0 as $sint) as $uint)
                 }
             }
+        }
 
-            fn wrapping_rem_u(self, other: Self) -> Self {
-                if other.0 == 0 {
-                    self
-                } else {
-                    Self(self.0.wrapping_rem(other.0))
-                }
-            }
-
-            fn wrapping_shl(self, other: u32) -> Self {
-                Self(self.0.wrapping_shl(other))
+        impl layers::Widening for $name {
+            fn hi(self) -> Self {
+                self >> (Self::BITS >> 1)
             }
 
-            fn wrapping_shr(self, other: u32) -> Self {
-                Self(self.0.wrapping_shr(other))
+            fn lo(self) -> Self {
+                self & (Self::MAX_U >> (Self::BITS >> 1))
             }
 
-            fn wrapping_sra(self, other: u32) -> Self {
-                Self((self.0 as $sint).wrapping_shr(other) as $uint)
+            fn lo_sext(self) -> Self {
+                self.sext((Self::BITS >> 1) - 1)
             }
 
-            fn wrapping_sub(self, other: Self) -> Self {
-                Self(self.0.wrapping_sub(other.0))
+            fn widening_mul_u(self, other: Self) -> (Self, Self) {
+                self._widening_mul_u(other)
             }
         }
     };
@@ -775,6 +1738,28 @@ impl_widening_mul_u_wrap!(E8, u16);
 impl_widening_mul_u_wrap!(E16, u32);
 impl_widening_mul_u_wrap!(E32, u64);
 impl_widening_mul_u_wrap!(E64, u128);
+
+macro_rules! construct_eint_lane {
+    ($name:ty) => {
+        impl Lane for $name {
+            const WIDTH: u32 = <$name as layers::EintBase>::BITS;
+
+            fn from_limb(x: u64) -> Self {
+                Self::from(x)
+            }
+
+            fn to_limb(self) -> u64 {
+                self.u64()
+            }
+        }
+    };
+}
+
+construct_eint_lane!(E8);
+construct_eint_lane!(E16);
+construct_eint_lane!(E32);
+construct_eint_lane!(E64);
+
 uint_wrap_from_impl!(E16, E8);
 uint_wrap_from_impl!(E32, E8);
 uint_wrap_from_impl!(E32, E16);
@@ -786,7 +1771,7 @@ uint_wrap_from_impl!(E128, E16);
 uint_wrap_from_impl!(E128, E32);
 uint_wrap_from_impl!(E128, E64);
 
-impl EintWideningMulU for E128 {
+impl E128 {
     fn _widening_mul_u(self, other: Self) -> (Self, Self) {
         let x0 = self.lo();
         let x1 = self.hi();
@@ -807,14 +1792,10 @@ macro_rules! construct_eint_twin_from_uint {
     ($name:ident, $from:ty) => {
         impl core::convert::From<$from> for $name {
             fn from(small: $from) -> Self {
+                let x = small as u128;
                 let mut b = [u64::MIN; Self::BITS as usize >> 6];
-                unsafe {
-                    core::ptr::copy_nonoverlapping(
-                        &small as *const $from as *const u8,
-                        b.as_mut_ptr() as *mut u8,
-                        Self::BITS as usize >> 3,
-                    );
-                }
+                b[0] = x as u64;
+                b[1] = (x >> 64) as u64;
                 Self(b)
             }
         }
@@ -825,18 +1806,14 @@ macro_rules! construct_eint_twin_from_sint {
     ($name:ident, $from:ty) => {
         impl core::convert::From<$from> for $name {
             fn from(small: $from) -> Self {
+                let x = small as i128 as u128;
                 let mut b = if small >= 0 {
                     [u64::MIN; Self::BITS as usize >> 6]
                 } else {
                     [u64::MAX; Self::BITS as usize >> 6]
                 };
-                unsafe {
-                    core::ptr::copy_nonoverlapping(
-                        &small as *const $from as *const u8,
-                        b.as_mut_ptr() as *mut u8,
-                        Self::BITS as usize >> 3,
-                    );
-                }
+                b[0] = x as u64;
+                b[1] = (x >> 64) as u64;
                 Self(b)
             }
         }
@@ -846,8 +1823,16 @@ macro_rules! construct_eint_twin_from_sint {
 macro_rules! construct_eint_twin {
     ($name:ident, $size:expr) => {
         #[derive(Copy, Clone, Default, PartialEq, Eq)]
+        #[cfg_attr(feature = "zerocopy", derive(::zerocopy::AsBytes, ::zerocopy::FromBytes, ::zerocopy::FromZeroes))]
+        #[repr(transparent)]
         pub struct $name(pub [u64; $size]);
 
+        impl core::convert::From<Signed<$name>> for $name {
+            fn from(x: Signed<$name>) -> Self {
+                x.0
+            }
+        }
+
         impl core::convert::From<bool> for $name {
             fn from(small: bool) -> Self {
                 if small {
@@ -868,6 +1853,18 @@ macro_rules! construct_eint_twin {
         construct_eint_twin_from_uint!($name, u32);
         construct_eint_twin_from_uint!($name, u64);
         construct_eint_twin_from_uint!($name, u128);
+        construct_eint_ops!($name);
+        construct_eint_shift!($name, i8);
+        construct_eint_shift!($name, i16);
+        construct_eint_shift!($name, i32);
+        construct_eint_shift!($name, i64);
+        construct_eint_shift!($name, i128);
+        construct_eint_shift!($name, isize);
+        construct_eint_shift!($name, u8);
+        construct_eint_shift!($name, u16);
+        construct_eint_shift!($name, u64);
+        construct_eint_shift!($name, u128);
+        construct_eint_shift!($name, usize);
 
         impl core::cmp::PartialOrd for $name {
             fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
@@ -1056,27 +2053,93 @@ macro_rules! construct_eint_twin {
             }
         }
 
-        impl Eint for $name {
+        impl private::Sealed for $name {}
+
+        impl layers::EintBase for $name {
             const BITS: u32 = $size * 64;
+
             const MAX_S: Self = {
                 let mut b = [u64::MAX; $size as usize];
                 b[$size as usize - 1] = i64::MAX as u64;
                 Self(b)
             };
+
             const MAX_U: Self = Self([u64::MAX; $size]);
+
             const MIN_S: Self = {
                 let mut b = [u64::MIN; $size as usize];
                 b[$size as usize - 1] = i64::MIN as u64;
                 Self(b)
             };
+
             const MIN_U: Self = Self([u64::MIN; $size]);
+
             const ONE: Self = {
                 let mut b = [u64::MIN; $size as usize];
                 b[0] = 1;
                 Self(b)
             };
+
             const ZERO: Self = Self([u64::MIN; $size]);
 
+            fn get(mem: &[u8]) -> Self {
+                let mut b = [0u64; $size];
+                unsafe {
+                    core::ptr::copy_nonoverlapping(
+                        mem.as_ptr() as *const u8,
+                        b.as_mut_ptr() as *mut u8,
+                        <Self as layers::EintBase>::BITS as usize >> 3,
+                    );
+                }
+                Self(b)
+            }
+
+            fn limb(&self, i: usize) -> u64 {
+                self.0[i]
+            }
+
+            fn put(&self, mem: &mut [u8]) {
+                unsafe {
+                    core::ptr::copy_nonoverlapping(
+                        self.0.as_ptr() as *const u8,
+                        mem.as_mut_ptr(),
+                        <Self as layers::EintBase>::BITS as usize >> 3,
+                    );
+                }
+            }
+
+            fn put_lo(&self, mem: &mut [u8]) {
+                unsafe {
+                    core::ptr::copy_nonoverlapping(
+                        self.0.as_ptr() as *const u8,
+                        mem.as_mut_ptr(),
+                        <Self as layers::EintBase>::BITS as usize >> 4,
+                    );
+                }
+            }
+
+            fn set_limb(&mut self, i: usize, v: u64) {
+                self.0[i] = v;
+            }
+
+            fn u8(self) -> u8 {
+                self.0[0] as u8
+            }
+
+            fn u16(self) -> u16 {
+                self.0[0] as u16
+            }
+
+            fn u32(self) -> u32 {
+                self.0[0] as u32
+            }
+
+            fn u64(self) -> u64 {
+                self.0[0]
+            }
+        }
+
+        impl layers::BitOps for $name {
             fn bit(&self, n: u32) -> bool {
                 let n = n % Self::BITS;
                 self.0[n as usize / 64] & (1 << (n % 64)) != 0
@@ -1092,6 +2155,14 @@ macro_rules! construct_eint_twin {
                 self.0[n as usize / 64] |= 1 << (n % 64)
             }
 
+            fn brev8(self) -> Self {
+                let mut b = [0u64; $size];
+                for i in 0..$size {
+                    b[i] = self.0[i].reverse_bits().swap_bytes();
+                }
+                Self(b)
+            }
+
             fn clz(&self) -> u32 {
                 let mut r = 0;
                 for i in 0..$size {
@@ -1106,21 +2177,6 @@ macro_rules! construct_eint_twin {
                 r
             }
 
-            fn cmp_s(&self, other: &Self) -> core::cmp::Ordering {
-                let l_sign = self.is_negative();
-                let r_sign = other.is_negative();
-                match (l_sign, r_sign) {
-                    (false, false) => self.cmp(&other),
-                    (false, true) => core::cmp::Ordering::Greater,
-                    (true, false) => core::cmp::Ordering::Less,
-                    (true, true) => self.cmp(&other),
-                }
-            }
-
-            fn cmp_u(&self, other: &Self) -> core::cmp::Ordering {
-                self.0.iter().rev().cmp(other.0.iter().rev())
-            }
-
             fn cpop(&self) -> u32 {
                 let mut r = 0;
                 for i in 0..$size {
@@ -1143,103 +2199,165 @@ macro_rules! construct_eint_twin {
                 r
             }
 
-            fn get(mem: &[u8]) -> Self {
+            fn leading_ones(&self) -> u32 {
+                let mut r = 0;
+                for i in 0..$size {
+                    let w = self.0[$size - i - 1];
+                    if w == u64::MAX {
+                        r += 64;
+                    } else {
+                        r += w.leading_ones();
+                        break;
+                    }
+                }
+                r
+            }
+
+            fn pdep(self, mask: Self) -> Self {
                 let mut b = [0u64; $size];
-                unsafe {
-                    core::ptr::copy_nonoverlapping(
-                        mem.as_ptr() as *const u8,
-                        b.as_mut_ptr() as *mut u8,
-                        Self::BITS as usize >> 3,
-                    );
+                let mut k = 0;
+                for i in 0..$size {
+                    let mut m = mask.0[i];
+                    while m != 0 {
+                        b[i] |= ((self.0[k / 64] >> (k % 64)) & 1) << m.trailing_zeros();
+                        m &= m - 1;
+                        k += 1;
+                    }
                 }
                 Self(b)
             }
 
-            fn hi(self) -> Self {
+            fn pext(self, mask: Self) -> Self {
                 let mut b = [0u64; $size];
-                b[0..$size / 2].copy_from_slice(&self.0[$size / 2..$size]);
+                let mut k = 0;
+                for i in 0..$size {
+                    let mut m = mask.0[i];
+                    while m != 0 {
+                        b[k / 64] |= ((self.0[i] >> m.trailing_zeros()) & 1) << (k % 64);
+                        m &= m - 1;
+                        k += 1;
+                    }
+                }
                 Self(b)
             }
 
-            fn is_negative(&self) -> bool {
-                (self.0[$size - 1] as i64).is_negative()
+            fn reverse_bits(self) -> Self {
+                let mut b = [0u64; $size];
+                for i in 0..$size {
+                    b[i] = self.0[$size - i - 1].reverse_bits();
+                }
+                Self(b)
             }
 
-            fn is_positive(&self) -> bool {
-                (self.0[$size - 1] as i64).is_positive()
+            fn rotate_left(self, other: u32) -> Self {
+                let shamt = other % Self::BITS;
+                let mut b = [0u64; $size];
+                let elem_shift = shamt as usize / 64;
+                let bits_shift = shamt as usize % 64;
+                for i in 0..$size {
+                    b[(i + elem_shift) % $size] = self.0[i] << bits_shift;
+                }
+                if bits_shift != 0 {
+                    for i in 0..$size {
+                        b[(i + elem_shift + 1) % $size] |= self.0[i] >> (64 - bits_shift);
+                    }
+                }
+                Self(b)
             }
 
-            fn lo(self) -> Self {
+            fn rotate_right(self, other: u32) -> Self {
+                let shamt = other % Self::BITS;
                 let mut b = [0u64; $size];
-                b[0..$size / 2].copy_from_slice(&self.0[0..$size / 2]);
+                let elem_shift = shamt as usize / 64;
+                let bits_shift = shamt as usize % 64;
+                for i in 0..$size {
+                    b[(i + $size - elem_shift) % $size] = self.0[i] >> bits_shift;
+                }
+                if bits_shift != 0 {
+                    for i in 0..$size {
+                        b[(i + 2 * $size - elem_shift - 1) % $size] |= self.0[i] << (64 - bits_shift);
+                    }
+                }
                 Self(b)
             }
 
-            fn lo_sext(self) -> Self {
-                if (self.0[$size / 2 - 1] as i64).is_negative() {
-                    let mut b = Self::MAX_U.0;
-                    b[0..$size / 2].copy_from_slice(&self.0[0..$size / 2]);
-                    Self(b)
-                } else {
-                    self
+            fn swap_bytes(self) -> Self {
+                let mut b = [0u64; $size];
+                for i in 0..$size {
+                    b[i] = self.0[$size - i - 1].swap_bytes();
                 }
+                Self(b)
             }
 
-            fn overflowing_add_s(self, other: Self) -> (Self, bool) {
-                let r = self.wrapping_add(other);
-                if self.is_negative() == other.is_negative() {
-                    (r, r.is_negative() != self.is_negative())
-                } else {
-                    (r, false)
+            fn trailing_ones(&self) -> u32 {
+                let mut r = 0;
+                for i in 0..$size {
+                    let w = self.0[i];
+                    if w == u64::MAX {
+                        r += 64;
+                    } else {
+                        r += w.trailing_ones();
+                        break;
+                    }
+                }
+                r
+            }
+
+            fn widening_clmul(self, other: Self) -> (Self, Self) {
+                let mut lh = [0u64; $size * 2];
+                clmul_limbs(&self.0, &other.0, &mut lh);
+                let mut lo = [0u64; $size];
+                lo.copy_from_slice(&lh[0..$size]);
+                let mut hi = [0u64; $size];
+                hi.copy_from_slice(&lh[$size..$size * 2]);
+                (Self(lo), Self(hi))
+            }
+
+            fn wrapping_shl(self, other: u32) -> Self {
+                let shamt = other % Self::BITS;
+                let mut b = [0u64; $size];
+                let elem_shift = shamt as usize / 64;
+                let bits_shift = shamt as usize % 64;
+                for i in elem_shift..$size {
+                    b[i] = self.0[i - elem_shift] << bits_shift;
+                }
+                if bits_shift != 0 {
+                    for i in elem_shift + 1..$size {
+                        b[i] += self.0[i - 1 - elem_shift] >> (64 - bits_shift);
+                    }
                 }
+                Self(b)
             }
 
-            fn overflowing_add_u(self, other: Self) -> (Self, bool) {
+            fn wrapping_shr(self, other: u32) -> Self {
+                let shamt = other % Self::BITS;
                 let mut b = [0u64; $size];
-                let mut carry = false;
-                for i in 0..$size {
-                    let (r0, carry0) = self.0[i].overflowing_add(other.0[i]);
-                    let (r1, carry1) = r0.overflowing_add(carry as u64);
-                    b[i] = r1;
-                    carry = carry0 | carry1
+                let elem_shift = shamt as usize / 64;
+                let bits_shift = shamt as usize % 64;
+                for i in elem_shift..$size {
+                    b[i - elem_shift] = self.0[i] >> bits_shift;
                 }
-                (Self(b), carry)
-            }
-
-            fn overflowing_mul_s(self, other: Self) -> (Self, bool) {
-                let (lo, hi) = self.widening_mul_s(other);
-                if !hi.is_negative() {
-                    if hi != Self::MIN_U || lo.is_negative() {
-                        return (lo, true);
-                    } else {
-                        return (lo, false);
-                    }
-                } else {
-                    if hi != Self::MAX_U || lo < Self::MIN_S {
-                        return (lo, true);
-                    } else {
-                        return (lo, false);
+                if bits_shift != 0 {
+                    for i in elem_shift + 1..$size {
+                        b[i - elem_shift - 1] += self.0[i] << (64 - bits_shift);
                     }
                 }
+                Self(b)
             }
 
-            fn overflowing_mul_u(self, other: Self) -> (Self, bool) {
-                let (lo, hi) = self.widening_mul_u(other);
-                (lo, hi != Self::ZERO)
-            }
-
-            fn overflowing_sub_s(self, other: Self) -> (Self, bool) {
-                let r = self.wrapping_sub(other);
-                if self.is_negative() == other.is_negative() {
-                    (r, false)
-                } else {
-                    (r, r.is_negative() != self.is_negative())
-                }
+            fn wrapping_sra(self, other: u32) -> Self {
+                let shamt = other % Self::BITS;
+                let hi =
+                    if self.is_negative() && shamt != 0 { Self::MAX_U << (Self::BITS - shamt) } else { Self::MIN_U };
+                let lo = layers::BitOps::wrapping_shr(self, shamt);
+                hi | lo
             }
+        }
 
-            fn overflowing_sub_u(self, other: Self) -> (Self, bool) {
+        impl layers::UnsignedArith for $name {
+            fn borrowing_sub(self, other: Self, borrow: bool) -> (Self, bool) {
                 let mut b = [0u64; $size];
-                let mut borrow = false;
+                let mut borrow = borrow;
                 for i in 0..$size {
                     let (r0, borrow0) = self.0[i].overflowing_sub(other.0[i]);
                     let (r1, borrow1) = r0.overflowing_sub(borrow as u64);
@@ -1249,40 +2367,46 @@ macro_rules! construct_eint_twin {
                 (Self(b), borrow)
             }
 
-            fn put(&self, mem: &mut [u8]) {
-                unsafe {
-                    core::ptr::copy_nonoverlapping(
-                        self.0.as_ptr() as *const u8,
-                        mem.as_mut_ptr(),
-                        Self::BITS as usize >> 3,
-                    );
+            fn carrying_add(self, other: Self, carry: bool) -> (Self, bool) {
+                let mut b = [0u64; $size];
+                let mut carry = carry;
+                for i in 0..$size {
+                    let (r0, carry0) = self.0[i].overflowing_add(other.0[i]);
+                    let (r1, carry1) = r0.overflowing_add(carry as u64);
+                    b[i] = r1;
+                    carry = carry0 | carry1
                 }
+                (Self(b), carry)
             }
 
-            fn put_lo(&self, mem: &mut [u8]) {
-                unsafe {
-                    core::ptr::copy_nonoverlapping(
-                        self.0.as_ptr() as *const u8,
-                        mem.as_mut_ptr(),
-                        Self::BITS as usize >> 4,
-                    );
-                }
+            fn cmp_u(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.iter().rev().cmp(other.0.iter().rev())
             }
 
-            fn u8(self) -> u8 {
-                self.0[0] as u8
+            fn mul_add(self, other: Self, acc: Self) -> Self {
+                let mut b = acc.0;
+                for i in 0..$size {
+                    let mut c = 0u64;
+                    for j in 0..$size - i {
+                        let uv: u128 = (self.0[j] as u128) * other.0[i] as u128 + b[i + j] as u128 + c as u128;
+                        b[i + j] = uv as u64;
+                        c = (uv >> 64) as u64;
+                    }
+                }
+                Self(b)
             }
 
-            fn u16(self) -> u16 {
-                self.0[0] as u16
+            fn overflowing_add_u(self, other: Self) -> (Self, bool) {
+                layers::UnsignedArith::carrying_add(self, other, false)
             }
 
-            fn u32(self) -> u32 {
-                self.0[0] as u32
+            fn overflowing_mul_u(self, other: Self) -> (Self, bool) {
+                let (lo, hi) = self.widening_mul_u(other);
+                (lo, hi != Self::ZERO)
             }
 
-            fn u64(self) -> u64 {
-                self.0[0]
+            fn overflowing_sub_u(self, other: Self) -> (Self, bool) {
+                layers::UnsignedArith::borrowing_sub(self, other, false)
             }
 
             fn wrapping_add(self, other: Self) -> Self {
@@ -1297,16 +2421,6 @@ macro_rules! construct_eint_twin {
                 Self(b)
             }
 
-            fn wrapping_div_s(self, other: Self) -> Self {
-                if other == Self::MIN_U {
-                    Self::MAX_U
-                } else if self == Self::MIN_S && other == Self::MAX_U {
-                    Self::MIN_S
-                } else {
-                    self.div_s(other).0
-                }
-            }
-
             fn wrapping_div_u(self, other: Self) -> Self {
                 if other == Self::MIN_U {
                     Self::MAX_U
@@ -1332,76 +2446,157 @@ macro_rules! construct_eint_twin {
                 Self(b)
             }
 
-            fn wrapping_rem_s(self, other: Self) -> Self {
-                let minus_min = Self::ONE << (Self::BITS - 1);
-                let minus_one = Self::MAX_U;
+            fn wrapping_rem_u(self, other: Self) -> Self {
                 if other == Self::MIN_U {
                     self
-                } else if self == minus_min && other == minus_one {
-                    Self::MIN_U
                 } else {
-                    self.div_s(other).1
+                    self.div_u(other).1
                 }
             }
 
-            fn wrapping_rem_u(self, other: Self) -> Self {
+            fn wrapping_sub(self, other: Self) -> Self {
+                let mut b = [0u64; $size];
+                let mut borrow = false;
+                for i in 0..$size {
+                    let (r0, borrow0) = self.0[i].overflowing_sub(other.0[i]);
+                    let (r1, borrow1) = r0.overflowing_sub(borrow as u64);
+                    b[i] = r1;
+                    borrow = borrow0 | borrow1
+                }
+                Self(b)
+            }
+        }
+
+        impl layers::SignedArith for $name {
+            fn cmp_s(&self, other: &Self) -> core::cmp::Ordering {
+                let l_sign = layers::SignedArith::is_negative(self);
+                let r_sign = layers::SignedArith::is_negative(other);
+                match (l_sign, r_sign) {
+                    (false, false) => self.cmp(&other),
+                    (false, true) => core::cmp::Ordering::Greater,
+                    (true, false) => core::cmp::Ordering::Less,
+                    (true, true) => self.cmp(&other),
+                }
+            }
+
+            fn is_negative(&self) -> bool {
+                (self.0[$size - 1] as i64).is_negative()
+            }
+
+            fn is_positive(&self) -> bool {
+                (self.0[$size - 1] as i64).is_positive()
+            }
+
+            fn overflowing_add_s(self, other: Self) -> (Self, bool) {
+                let r = self.wrapping_add(other);
+                if layers::SignedArith::is_negative(&self) == layers::SignedArith::is_negative(&other) {
+                    (r, layers::SignedArith::is_negative(&r) != layers::SignedArith::is_negative(&self))
+                } else {
+                    (r, false)
+                }
+            }
+
+            fn overflowing_mul_s(self, other: Self) -> (Self, bool) {
+                let (lo, hi) = self.widening_mul_s(other);
+                if !layers::SignedArith::is_negative(&hi) {
+                    if hi != <Self as layers::EintBase>::MIN_U || layers::SignedArith::is_negative(&lo) {
+                        return (lo, true);
+                    } else {
+                        return (lo, false);
+                    }
+                } else {
+                    if hi != Self::MAX_U || lo < Self::MIN_S {
+                        return (lo, true);
+                    } else {
+                        return (lo, false);
+                    }
+                }
+            }
+
+            fn overflowing_sub_s(self, other: Self) -> (Self, bool) {
+                let r = self.wrapping_sub(other);
+                if layers::SignedArith::is_negative(&self) == layers::SignedArith::is_negative(&other) {
+                    (r, false)
+                } else {
+                    (r, layers::SignedArith::is_negative(&r) != layers::SignedArith::is_negative(&self))
+                }
+            }
+
+            fn wrapping_div_s(self, other: Self) -> Self {
+                if other == Self::MIN_U {
+                    Self::MAX_U
+                } else if self == Self::MIN_S && other == Self::MAX_U {
+                    Self::MIN_S
+                } else {
+                    self.div_s(other).0
+                }
+            }
+
+            fn wrapping_rem_s(self, other: Self) -> Self {
+                let minus_min = Self::ONE << (Self::BITS - 1);
+                let minus_one = Self::MAX_U;
                 if other == Self::MIN_U {
                     self
+                } else if self == minus_min && other == minus_one {
+                    Self::MIN_U
                 } else {
-                    self.div_u(other).1
+                    self.div_s(other).1
                 }
             }
+        }
 
-            fn wrapping_shl(self, other: u32) -> Self {
-                let shamt = other % Self::BITS;
+        impl layers::Widening for $name {
+            fn hi(self) -> Self {
                 let mut b = [0u64; $size];
-                let elem_shift = shamt as usize / 64;
-                let bits_shift = shamt as usize % 64;
-                for i in elem_shift..$size {
-                    b[i] = self.0[i - elem_shift] << bits_shift;
-                }
-                if bits_shift != 0 {
-                    for i in elem_shift + 1..$size {
-                        b[i] += self.0[i - 1 - elem_shift] >> (64 - bits_shift);
-                    }
-                }
+                b[0..$size / 2].copy_from_slice(&self.0[$size / 2..$size]);
                 Self(b)
             }
 
-            fn wrapping_shr(self, other: u32) -> Self {
-                let shamt = other % Self::BITS;
+            fn lo(self) -> Self {
                 let mut b = [0u64; $size];
-                let elem_shift = shamt as usize / 64;
-                let bits_shift = shamt as usize % 64;
-                for i in elem_shift..$size {
-                    b[i - elem_shift] = self.0[i] >> bits_shift;
-                }
-                if bits_shift != 0 {
-                    for i in elem_shift + 1..$size {
-                        b[i - elem_shift - 1] += self.0[i] << (64 - bits_shift);
-                    }
-                }
+                b[0..$size / 2].copy_from_slice(&self.0[0..$size / 2]);
                 Self(b)
             }
 
-            fn wrapping_sra(self, other: u32) -> Self {
-                let shamt = other % Self::BITS;
-                let hi =
-                    if self.is_negative() && shamt != 0 { Self::MAX_U << (Self::BITS - shamt) } else { Self::MIN_U };
-                let lo = self.wrapping_shr(shamt);
-                hi | lo
+            fn lo_sext(self) -> Self {
+                if (self.0[$size / 2 - 1] as i64).is_negative() {
+                    let mut b = Self::MAX_U.0;
+                    b[0..$size / 2].copy_from_slice(&self.0[0..$size / 2]);
+                    Self(b)
+                } else {
+                    self
+                }
             }
 
-            fn wrapping_sub(self, other: Self) -> Self {
-                let mut b = [0u64; $size];
-                let mut borrow = false;
+            fn widening_mul_add_u(self, other: Self, acc_lo: Self, acc_hi: Self) -> (Self, Self) {
+                let mut lh = [0u64; $size * 2];
+                lh[..$size].copy_from_slice(&acc_lo.0);
+                lh[$size..].copy_from_slice(&acc_hi.0);
                 for i in 0..$size {
-                    let (r0, borrow0) = self.0[i].overflowing_sub(other.0[i]);
-                    let (r1, borrow1) = r0.overflowing_sub(borrow as u64);
-                    b[i] = r1;
-                    borrow = borrow0 | borrow1
+                    let mut c = 0u64;
+                    for j in 0..$size {
+                        let uv: u128 = (self.0[j] as u128) * other.0[i] as u128 + lh[i + j] as u128 + c as u128;
+                        lh[i + j] = uv as u64;
+                        c = (uv >> 64) as u64;
+                    }
+                    for e in lh[i + $size..].iter_mut() {
+                        if c == 0 {
+                            break;
+                        }
+                        let (r, carry) = e.overflowing_add(c);
+                        *e = r;
+                        c = carry as u64;
+                    }
                 }
-                Self(b)
+                let mut lo = [0u64; $size];
+                lo.copy_from_slice(&lh[..$size]);
+                let mut hi = [0u64; $size];
+                hi.copy_from_slice(&lh[$size..]);
+                (Self(lo), Self(hi))
+            }
+
+            fn widening_mul_u(self, other: Self) -> (Self, Self) {
+                self._widening_mul_u(other)
             }
         }
 
@@ -1478,6 +2673,27 @@ uint_twin_from_impl!(E2048, E256);
 uint_twin_from_impl!(E2048, E512);
 uint_twin_from_impl!(E2048, E1024);
 
+macro_rules! construct_eint_widen {
+    ($half:ident, $double:ident) => {
+        impl Widen for $half {
+            type Double = $double;
+        }
+
+        impl Narrow for $double {
+            type Half = $half;
+        }
+    };
+}
+
+construct_eint_widen!(E8, E16);
+construct_eint_widen!(E16, E32);
+construct_eint_widen!(E32, E64);
+construct_eint_widen!(E64, E128);
+construct_eint_widen!(E128, E256);
+construct_eint_widen!(E256, E512);
+construct_eint_widen!(E512, E1024);
+construct_eint_widen!(E1024, E2048);
+
 use uint::construct_uint;
 
 construct_uint! { struct U256(4); }
@@ -0,0 +1,69 @@
+//! num-bigint support.
+//!
+//! BigUint conversions treat the integer as unsigned, BigInt conversions treat it as a two's complement signed
+//! integer. Narrowing conversions fail if the value is out of range for the respective interpretation.
+
+use crate::*;
+use ::num_bigint::{BigInt, BigUint, Sign};
+use alloc::vec::Vec;
+
+fn from_biguint<T: Eint>(u: &BigUint) -> Result<T, TryFromEintError> {
+    let n = T::BITS as usize >> 3;
+    let b: Vec<u8> = u.to_bytes_le();
+    if b.len() > n {
+        return Err(TryFromEintError(()));
+    }
+    let mut mem = [0u8; 256];
+    mem[..b.len()].copy_from_slice(&b);
+    Ok(T::get(&mem[..n]))
+}
+
+fn from_bigint<T: Eint>(i: &BigInt) -> Result<T, TryFromEintError> {
+    let n = T::BITS as usize >> 3;
+    let b: Vec<u8> = i.to_signed_bytes_le();
+    if b.len() > n {
+        return Err(TryFromEintError(()));
+    }
+    let mut mem = if i.sign() == Sign::Minus { [0xffu8; 256] } else { [0u8; 256] };
+    mem[..b.len()].copy_from_slice(&b);
+    Ok(T::get(&mem[..n]))
+}
+
+macro_rules! impl_num_bigint {
+    ($name:ty) => {
+        impl From<$name> for BigUint {
+            fn from(e: $name) -> Self {
+                let mut mem = [0u8; 256];
+                let n = <$name>::BITS as usize >> 3;
+                e.put(&mut mem[..n]);
+                BigUint::from_bytes_le(&mem[..n])
+            }
+        }
+
+        impl TryFrom<&BigUint> for $name {
+            type Error = TryFromEintError;
+
+            fn try_from(u: &BigUint) -> Result<Self, Self::Error> {
+                from_biguint(u)
+            }
+        }
+
+        impl TryFrom<&BigInt> for $name {
+            type Error = TryFromEintError;
+
+            fn try_from(i: &BigInt) -> Result<Self, Self::Error> {
+                from_bigint(i)
+            }
+        }
+    };
+}
+
+impl_num_bigint!(E8);
+impl_num_bigint!(E16);
+impl_num_bigint!(E32);
+impl_num_bigint!(E64);
+impl_num_bigint!(E128);
+impl_num_bigint!(E256);
+impl_num_bigint!(E512);
+impl_num_bigint!(E1024);
+impl_num_bigint!(E2048);
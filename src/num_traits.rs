@@ -0,0 +1,440 @@
+//! num-traits support.
+//!
+//! All traits treat the integer as unsigned: Bounded uses MIN_U and MAX_U, checked and saturating operations detect
+//! unsigned overflow, and NumCast rejects negative inputs. PrimInt::signed_shr is an arithmetic shift and
+//! PrimInt::pow wraps around at the boundary of the type. CheckedNeg::checked_neg returns Some only for zero, the one
+//! value whose unsigned negation does not overflow.
+
+use crate::*;
+use ::num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
+use ::num_traits::*;
+
+/// An error which can be returned when parsing an integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseEintError {
+    /// The string is empty.
+    Empty,
+    /// The string contains a character that is not a digit in the given radix.
+    InvalidDigit,
+    /// The value does not fit in the type.
+    Overflow,
+}
+
+impl core::fmt::Display for ParseEintError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseEintError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseEintError::InvalidDigit => write!(f, "invalid digit found in string"),
+            ParseEintError::Overflow => write!(f, "number too large to fit in target type"),
+        }
+    }
+}
+
+fn from_str_radix<T: Eint>(s: &str, radix: u32) -> Result<T, ParseEintError> {
+    assert!((2..=36).contains(&radix), "from_str_radix: radix must lie in the range `[2, 36]`");
+    let s = s.strip_prefix('+').unwrap_or(s);
+    if s.is_empty() {
+        return Err(ParseEintError::Empty);
+    }
+    let mut r = T::MIN_U;
+    for c in s.chars() {
+        let d = c.to_digit(radix).ok_or(ParseEintError::InvalidDigit)?;
+        let (m, overflow_mul) = r.overflowing_mul_u(T::from(radix));
+        let (a, overflow_add) = m.overflowing_add_u(T::from(d));
+        if overflow_mul || overflow_add {
+            return Err(ParseEintError::Overflow);
+        }
+        r = a;
+    }
+    Ok(r)
+}
+
+fn to_u128<T: Eint>(n: &T) -> Option<u128> {
+    if T::BITS <= 64 {
+        Some(n.u64() as u128)
+    } else if n.clz() >= T::BITS - 128 {
+        Some(n.u64() as u128 | (n.wrapping_shr(64).u64() as u128) << 64)
+    } else {
+        None
+    }
+}
+
+/// Reorders the little-endian bytes of an integer into the order of its memory representation, or back. E8 to E128
+/// are a single primitive and E256 to E2048 an array of u64, each stored in native byte order.
+fn native_order<T: Eint>(mem: &mut [u8]) {
+    if cfg!(target_endian = "big") {
+        let w = if T::BITS <= 128 { T::BITS as usize >> 3 } else { 8 };
+        mem.chunks_mut(w).for_each(|e| e.reverse());
+    }
+}
+
+/// Returns the integer whose memory representation holds x in big-endian byte order if big is true, otherwise in
+/// little-endian byte order.
+fn to_order<T: Eint>(x: T, big: bool) -> T {
+    let mut buf = [0u8; 256];
+    let mem = &mut buf[..T::BITS as usize >> 3];
+    x.put(mem);
+    if big {
+        mem.reverse();
+    }
+    native_order::<T>(mem);
+    T::get(mem)
+}
+
+/// Reads back the integer held in the memory representation of x, the inverse of to_order.
+fn from_order<T: Eint>(x: T, big: bool) -> T {
+    let mut buf = [0u8; 256];
+    let mem = &mut buf[..T::BITS as usize >> 3];
+    x.put(mem);
+    native_order::<T>(mem);
+    if big {
+        mem.reverse();
+    }
+    T::get(mem)
+}
+
+macro_rules! impl_num_traits {
+    ($name:ty) => {
+        impl Bounded for $name {
+            fn min_value() -> Self {
+                Self::MIN_U
+            }
+
+            fn max_value() -> Self {
+                Self::MAX_U
+            }
+        }
+
+        impl CheckedAdd for $name {
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                match self.overflowing_add_u(*v) {
+                    (r, false) => Some(r),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        impl CheckedDiv for $name {
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                if *v == Self::MIN_U {
+                    None
+                } else {
+                    Some(self.wrapping_div_u(*v))
+                }
+            }
+        }
+
+        impl CheckedMul for $name {
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                match self.overflowing_mul_u(*v) {
+                    (r, false) => Some(r),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        impl CheckedNeg for $name {
+            fn checked_neg(&self) -> Option<Self> {
+                if *self == Self::MIN_U {
+                    Some(Self::MIN_U)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl CheckedRem for $name {
+            fn checked_rem(&self, v: &Self) -> Option<Self> {
+                if *v == Self::MIN_U {
+                    None
+                } else {
+                    Some(self.wrapping_rem_u(*v))
+                }
+            }
+        }
+
+        impl CheckedShl for $name {
+            fn checked_shl(&self, rhs: u32) -> Option<Self> {
+                if rhs < Self::BITS {
+                    Some(Eint::wrapping_shl(*self, rhs))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl CheckedShr for $name {
+            fn checked_shr(&self, rhs: u32) -> Option<Self> {
+                if rhs < Self::BITS {
+                    Some(Eint::wrapping_shr(*self, rhs))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl CheckedSub for $name {
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                match self.overflowing_sub_u(*v) {
+                    (r, false) => Some(r),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        impl FromPrimitive for $name {
+            fn from_i64(n: i64) -> Option<Self> {
+                u64::try_from(n).ok().and_then(Self::from_u64)
+            }
+
+            fn from_i128(n: i128) -> Option<Self> {
+                u128::try_from(n).ok().and_then(Self::from_u128)
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                Self::from_u128(n as u128)
+            }
+
+            fn from_u128(n: u128) -> Option<Self> {
+                if n.checked_shr(Self::BITS).unwrap_or(0) != 0 {
+                    None
+                } else {
+                    Some(<Self as core::convert::From<u128>>::from(n))
+                }
+            }
+        }
+
+        impl Num for $name {
+            type FromStrRadixErr = ParseEintError;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                from_str_radix(str, radix)
+            }
+        }
+
+        impl NumCast for $name {
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                n.to_u128().and_then(Self::from_u128)
+            }
+        }
+
+        impl One for $name {
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl OverflowingAdd for $name {
+            fn overflowing_add(&self, v: &Self) -> (Self, bool) {
+                self.overflowing_add_u(*v)
+            }
+        }
+
+        impl OverflowingMul for $name {
+            fn overflowing_mul(&self, v: &Self) -> (Self, bool) {
+                self.overflowing_mul_u(*v)
+            }
+        }
+
+        impl OverflowingSub for $name {
+            fn overflowing_sub(&self, v: &Self) -> (Self, bool) {
+                self.overflowing_sub_u(*v)
+            }
+        }
+
+        impl PrimInt for $name {
+            fn count_ones(self) -> u32 {
+                self.cpop()
+            }
+
+            fn count_zeros(self) -> u32 {
+                Self::BITS - self.cpop()
+            }
+
+            fn leading_ones(self) -> u32 {
+                Eint::leading_ones(&self)
+            }
+
+            fn leading_zeros(self) -> u32 {
+                self.clz()
+            }
+
+            fn trailing_ones(self) -> u32 {
+                Eint::trailing_ones(&self)
+            }
+
+            fn trailing_zeros(self) -> u32 {
+                self.ctz()
+            }
+
+            fn rotate_left(self, n: u32) -> Self {
+                Eint::rotate_left(self, n)
+            }
+
+            fn rotate_right(self, n: u32) -> Self {
+                Eint::rotate_right(self, n)
+            }
+
+            fn signed_shl(self, n: u32) -> Self {
+                Eint::wrapping_shl(self, n)
+            }
+
+            fn signed_shr(self, n: u32) -> Self {
+                self.wrapping_sra(n)
+            }
+
+            fn unsigned_shl(self, n: u32) -> Self {
+                Eint::wrapping_shl(self, n)
+            }
+
+            fn unsigned_shr(self, n: u32) -> Self {
+                Eint::wrapping_shr(self, n)
+            }
+
+            fn swap_bytes(self) -> Self {
+                Eint::swap_bytes(self)
+            }
+
+            fn reverse_bits(self) -> Self {
+                Eint::reverse_bits(self)
+            }
+
+            fn from_be(x: Self) -> Self {
+                from_order(x, true)
+            }
+
+            fn from_le(x: Self) -> Self {
+                from_order(x, false)
+            }
+
+            fn to_be(self) -> Self {
+                to_order(self, true)
+            }
+
+            fn to_le(self) -> Self {
+                to_order(self, false)
+            }
+
+            fn pow(self, exp: u32) -> Self {
+                let mut base = self;
+                let mut exp = exp;
+                let mut r = Self::ONE;
+                while exp != 0 {
+                    if exp & 1 != 0 {
+                        r = Eint::wrapping_mul(r, base);
+                    }
+                    base = Eint::wrapping_mul(base, base);
+                    exp >>= 1;
+                }
+                r
+            }
+        }
+
+        impl ::num_traits::Saturating for $name {
+            fn saturating_add(self, v: Self) -> Self {
+                self.saturating_add_u(v).0
+            }
+
+            fn saturating_sub(self, v: Self) -> Self {
+                self.saturating_sub_u(v).0
+            }
+        }
+
+        impl SaturatingAdd for $name {
+            fn saturating_add(&self, v: &Self) -> Self {
+                self.saturating_add_u(*v).0
+            }
+        }
+
+        impl SaturatingMul for $name {
+            fn saturating_mul(&self, v: &Self) -> Self {
+                match self.overflowing_mul_u(*v) {
+                    (r, false) => r,
+                    (_, true) => Self::MAX_U,
+                }
+            }
+        }
+
+        impl SaturatingSub for $name {
+            fn saturating_sub(&self, v: &Self) -> Self {
+                self.saturating_sub_u(*v).0
+            }
+        }
+
+        impl ToPrimitive for $name {
+            fn to_i64(&self) -> Option<i64> {
+                to_u128(self).and_then(|n| i64::try_from(n).ok())
+            }
+
+            fn to_i128(&self) -> Option<i128> {
+                to_u128(self).and_then(|n| i128::try_from(n).ok())
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                to_u128(self).and_then(|n| u64::try_from(n).ok())
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                to_u128(self)
+            }
+        }
+
+        impl Unsigned for $name {}
+
+        impl WrappingAdd for $name {
+            fn wrapping_add(&self, v: &Self) -> Self {
+                Eint::wrapping_add(*self, *v)
+            }
+        }
+
+        impl WrappingMul for $name {
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                Eint::wrapping_mul(*self, *v)
+            }
+        }
+
+        impl WrappingNeg for $name {
+            fn wrapping_neg(&self) -> Self {
+                -*self
+            }
+        }
+
+        impl WrappingShl for $name {
+            fn wrapping_shl(&self, rhs: u32) -> Self {
+                Eint::wrapping_shl(*self, rhs)
+            }
+        }
+
+        impl WrappingShr for $name {
+            fn wrapping_shr(&self, rhs: u32) -> Self {
+                Eint::wrapping_shr(*self, rhs)
+            }
+        }
+
+        impl WrappingSub for $name {
+            fn wrapping_sub(&self, v: &Self) -> Self {
+                Eint::wrapping_sub(*self, *v)
+            }
+        }
+
+        impl Zero for $name {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == Self::ZERO
+            }
+        }
+    };
+}
+
+impl_num_traits!(E8);
+impl_num_traits!(E16);
+impl_num_traits!(E32);
+impl_num_traits!(E64);
+impl_num_traits!(E128);
+impl_num_traits!(E256);
+impl_num_traits!(E512);
+impl_num_traits!(E1024);
+impl_num_traits!(E2048);
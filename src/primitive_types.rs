@@ -0,0 +1,53 @@
+use crate::*;
+
+macro_rules! impl_primitive_types {
+    ($name:ident, $uint:ident) => {
+        impl From<$name> for ::primitive_types::$uint {
+            fn from(e: $name) -> Self {
+                ::primitive_types::$uint(e.0)
+            }
+        }
+
+        impl From<::primitive_types::$uint> for $name {
+            fn from(u: ::primitive_types::$uint) -> Self {
+                Self(u.0)
+            }
+        }
+    };
+}
+
+impl_primitive_types!(E256, U256);
+impl_primitive_types!(E512, U512);
+
+impl From<E256> for ::primitive_types::U512 {
+    fn from(e: E256) -> Self {
+        Self::from(E512::from(e))
+    }
+}
+
+impl From<::primitive_types::U256> for E512 {
+    fn from(u: ::primitive_types::U256) -> Self {
+        Self::from(E256::from(u))
+    }
+}
+
+impl TryFrom<E512> for ::primitive_types::U256 {
+    type Error = TryFromEintError;
+
+    fn try_from(e: E512) -> Result<Self, Self::Error> {
+        if e.hi() != E512::MIN_U {
+            return Err(TryFromEintError(()));
+        }
+        let mut b = [0u64; 4];
+        b.copy_from_slice(&e.0[..4]);
+        Ok(Self(b))
+    }
+}
+
+impl TryFrom<::primitive_types::U512> for E256 {
+    type Error = TryFromEintError;
+
+    fn try_from(u: ::primitive_types::U512) -> Result<Self, Self::Error> {
+        ::primitive_types::U256::try_from(E512::from(u)).map(Self::from)
+    }
+}
@@ -0,0 +1,44 @@
+use crate::*;
+use ::proptest::prelude::*;
+
+/// Returns a strategy biased towards edge cases: 0, 1, MAX_U, MIN_S, MAX_S, single-bit values and values near limb
+/// boundaries. About half of the values are uniformly random.
+fn any_eint<T: Eint + 'static>() -> impl Strategy<Value = T> {
+    let step = if T::BITS > 64 { 64 } else { T::BITS / 2 };
+    prop_oneof![
+        1 => Just(T::ZERO),
+        1 => Just(T::ONE),
+        1 => Just(T::MAX_U),
+        1 => Just(T::MIN_S),
+        1 => Just(T::MAX_S),
+        1 => (0..T::BITS).prop_map(|n| T::ONE << n),
+        2 => (1..T::BITS / step, -1i8..=1, any::<bool>()).prop_map(move |(k, d, neg)| {
+            let r = (T::ONE << (k * step)).wrapping_add(T::from(d));
+            if neg { -r } else { r }
+        }),
+        8 => ::proptest::collection::vec(any::<u8>(), T::BITS as usize >> 3).prop_map(|mem| T::get(&mem)),
+    ]
+}
+
+macro_rules! impl_proptest {
+    ($name:ty) => {
+        impl Arbitrary for $name {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                any_eint().boxed()
+            }
+        }
+    };
+}
+
+impl_proptest!(E8);
+impl_proptest!(E16);
+impl_proptest!(E32);
+impl_proptest!(E64);
+impl_proptest!(E128);
+impl_proptest!(E256);
+impl_proptest!(E512);
+impl_proptest!(E1024);
+impl_proptest!(E2048);
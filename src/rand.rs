@@ -0,0 +1,95 @@
+//! Rand support.
+//!
+//! The Standard distribution yields integers with all bits uniformly random. Uniform ranges, such as
+//! rng.gen_range(a..b), use unsigned semantics.
+
+use crate::*;
+use ::rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use ::rand::distributions::{Distribution, Standard};
+use ::rand::Rng;
+
+/// Returns an integer whose lower n bits are uniformly random and whose other bits are zero.
+pub fn random_bits<T: Eint, R: Rng + ?Sized>(rng: &mut R, n: u32) -> T {
+    let mut mem = [0u8; 256];
+    let size = T::BITS as usize >> 3;
+    rng.fill_bytes(&mut mem[..size]);
+    T::get(&mem[..size]) & T::mask(n)
+}
+
+/// Returns an integer uniformly distributed in [0, bound). Uses rejection sampling, so the result is unbiased.
+///
+/// Panics if bound is zero.
+pub fn random_below<T: Eint, R: Rng + ?Sized>(rng: &mut R, bound: T) -> T {
+    assert!(bound != T::MIN_U, "random_below: bound must be non-zero");
+    let n = T::BITS - bound.wrapping_sub(T::ONE).clz();
+    loop {
+        let r = random_bits(rng, n);
+        if r < bound {
+            return r;
+        }
+    }
+}
+
+/// The back-end implementing UniformSampler for Eint types.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformEint<T> {
+    low: T,
+    range: T,
+}
+
+impl<T: Eint> UniformSampler for UniformEint<T> {
+    type X = T;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<T> + Sized,
+        B2: SampleBorrow<T> + Sized,
+    {
+        let low = *low.borrow();
+        let high = *high.borrow();
+        assert!(low < high, "Uniform::new called with `low >= high`");
+        UniformEint { low, range: high.wrapping_sub(low) }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<T> + Sized,
+        B2: SampleBorrow<T> + Sized,
+    {
+        let low = *low.borrow();
+        let high = *high.borrow();
+        assert!(low <= high, "Uniform::new_inclusive called with `low > high`");
+        UniformEint { low, range: high.wrapping_sub(low).wrapping_add(T::ONE) }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        if self.range == T::MIN_U {
+            return random_bits(rng, T::BITS);
+        }
+        self.low.wrapping_add(random_below(rng, self.range))
+    }
+}
+
+macro_rules! impl_rand {
+    ($name:ty) => {
+        impl Distribution<$name> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                random_bits(rng, <$name>::BITS)
+            }
+        }
+
+        impl SampleUniform for $name {
+            type Sampler = UniformEint<$name>;
+        }
+    };
+}
+
+impl_rand!(E8);
+impl_rand!(E16);
+impl_rand!(E32);
+impl_rand!(E64);
+impl_rand!(E128);
+impl_rand!(E256);
+impl_rand!(E512);
+impl_rand!(E1024);
+impl_rand!(E2048);
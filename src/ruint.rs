@@ -0,0 +1,45 @@
+use crate::*;
+
+macro_rules! impl_ruint {
+    ($name:ident, $size:expr) => {
+        impl From<$name> for ::ruint::Uint<{ $size * 64 }, $size> {
+            fn from(e: $name) -> Self {
+                Self::from_limbs(e.0)
+            }
+        }
+
+        impl From<::ruint::Uint<{ $size * 64 }, $size>> for $name {
+            fn from(u: ::ruint::Uint<{ $size * 64 }, $size>) -> Self {
+                Self(u.into_limbs())
+            }
+        }
+    };
+}
+
+macro_rules! impl_ruint_try_from {
+    ($name:ident, $size:expr) => {
+        impl TryFrom<$name> for ::ruint::Uint<{ $size * 64 }, $size> {
+            type Error = TryFromEintError;
+
+            fn try_from(e: $name) -> Result<Self, Self::Error> {
+                if e.0[$size..].iter().any(|&l| l != 0) {
+                    return Err(TryFromEintError(()));
+                }
+                let mut b = [0u64; $size];
+                b.copy_from_slice(&e.0[..$size]);
+                Ok(Self::from_limbs(b))
+            }
+        }
+    };
+}
+
+impl_ruint!(E256, 4);
+impl_ruint!(E512, 8);
+impl_ruint!(E1024, 16);
+impl_ruint!(E2048, 32);
+impl_ruint_try_from!(E512, 4);
+impl_ruint_try_from!(E1024, 4);
+impl_ruint_try_from!(E1024, 8);
+impl_ruint_try_from!(E2048, 4);
+impl_ruint_try_from!(E2048, 8);
+impl_ruint_try_from!(E2048, 16);
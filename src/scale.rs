@@ -0,0 +1,51 @@
+//! SCALE codec support. Integers are encoded as fixed-length little-endian byte arrays, the same layout SCALE uses
+//! for primitive integers.
+
+use crate::*;
+use ::parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+
+macro_rules! impl_scale {
+    ($name:ty) => {
+        impl Encode for $name {
+            fn size_hint(&self) -> usize {
+                <$name>::BITS as usize >> 3
+            }
+
+            fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+                let mut mem = [0u8; <$name>::BITS as usize >> 3];
+                self.put(&mut mem);
+                dest.write(&mem);
+            }
+
+            fn encoded_size(&self) -> usize {
+                <$name>::BITS as usize >> 3
+            }
+        }
+
+        impl EncodeLike for $name {}
+
+        impl Decode for $name {
+            fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+                let mut mem = [0u8; <$name>::BITS as usize >> 3];
+                input.read(&mut mem)?;
+                Ok(Self::get(&mem))
+            }
+        }
+
+        impl MaxEncodedLen for $name {
+            fn max_encoded_len() -> usize {
+                <$name>::BITS as usize >> 3
+            }
+        }
+    };
+}
+
+impl_scale!(E8);
+impl_scale!(E16);
+impl_scale!(E32);
+impl_scale!(E64);
+impl_scale!(E128);
+impl_scale!(E256);
+impl_scale!(E512);
+impl_scale!(E1024);
+impl_scale!(E2048);
@@ -0,0 +1,216 @@
+//! Serde support.
+//!
+//! Human-readable formats use a 0x-prefixed hex string, binary formats use a fixed-length little-endian byte array.
+//! The modules below select a specific representation through `#[serde(with = "eint::serde::decimal")]`.
+
+use crate::*;
+use ::serde::de::{Error, SeqAccess, Unexpected, Visitor};
+use ::serde::ser::SerializeTuple;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+struct BytesVisitor<T> {
+    be: bool,
+    ty: core::marker::PhantomData<T>,
+}
+
+impl<'de, T: Eint> Visitor<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a byte array of length {}", T::BITS >> 3)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<T, E> {
+        let n = T::BITS as usize >> 3;
+        if v.len() != n {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+        let mut mem = [0u8; 256];
+        mem[..n].copy_from_slice(v);
+        if self.be {
+            mem[..n].reverse();
+        }
+        Ok(T::get(&mem[..n]))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let n = T::BITS as usize >> 3;
+        let mut mem = [0u8; 256];
+        for (i, e) in mem[..n].iter_mut().enumerate() {
+            *e = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(i, &self))?;
+        }
+        if self.be {
+            mem[..n].reverse();
+        }
+        Ok(T::get(&mem[..n]))
+    }
+}
+
+fn serialize_bytes<S: Serializer>(mem: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut t = serializer.serialize_tuple(mem.len())?;
+    for e in mem {
+        t.serialize_element(e)?;
+    }
+    t.end()
+}
+
+struct DecimalVisitor<T>(core::marker::PhantomData<T>);
+
+impl<'de, T: Eint> Visitor<'de> for DecimalVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a decimal string of an unsigned {} bits integer", T::BITS)
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<T, E> {
+        if v.is_empty() {
+            return Err(E::invalid_value(Unexpected::Str(v), &self));
+        }
+        let mut r = T::MIN_U;
+        for c in v.bytes() {
+            if !c.is_ascii_digit() {
+                return Err(E::invalid_value(Unexpected::Str(v), &self));
+            }
+            let (m, overflow_mul) = r.overflowing_mul_u(T::from(10u8));
+            let (a, overflow_add) = m.overflowing_add_u(T::from(c - b'0'));
+            if overflow_mul || overflow_add {
+                return Err(E::invalid_value(Unexpected::Str(v), &self));
+            }
+            r = a;
+        }
+        Ok(r)
+    }
+}
+
+struct HexVisitor<T>(core::marker::PhantomData<T>);
+
+impl<'de, T: Eint> Visitor<'de> for HexVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a 0x-prefixed hex string of at most {} digits", T::BITS >> 2)
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<T, E> {
+        let n = T::BITS as usize >> 3;
+        let digits = match v.strip_prefix("0x") {
+            Some(digits) if !digits.is_empty() && digits.len() <= n * 2 => digits.as_bytes(),
+            _ => return Err(E::invalid_value(Unexpected::Str(v), &self)),
+        };
+        let mut mem = [0u8; 256];
+        for (i, c) in digits.iter().rev().enumerate() {
+            let d = match (*c as char).to_digit(16) {
+                Some(d) => d as u8,
+                None => return Err(E::invalid_value(Unexpected::Str(v), &self)),
+            };
+            mem[i >> 1] |= d << ((i & 1) << 2);
+        }
+        Ok(T::get(&mem[..n]))
+    }
+}
+
+/// Serialize as a big-endian fixed-length byte array.
+pub mod be_bytes {
+    use super::*;
+
+    pub fn serialize<T: Eint, S: Serializer>(v: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let n = T::BITS as usize >> 3;
+        let mut mem = [0u8; 256];
+        v.put(&mut mem[..n]);
+        mem[..n].reverse();
+        serialize_bytes(&mem[..n], serializer)
+    }
+
+    pub fn deserialize<'de, T: Eint, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_tuple(T::BITS as usize >> 3, BytesVisitor { be: true, ty: core::marker::PhantomData })
+    }
+}
+
+/// Serialize as an unsigned decimal string.
+pub mod decimal {
+    use super::*;
+
+    pub fn serialize<T: Eint, S: Serializer>(v: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buf = [0u8; 640];
+        serializer.serialize_str(crate::write_decimal_u(*v, &mut buf))
+    }
+
+    pub fn deserialize<'de, T: Eint, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(DecimalVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Serialize as a 0x-prefixed, zero-padded lowercase hex string.
+pub mod hex {
+    use super::*;
+
+    pub fn serialize<T: Eint, S: Serializer>(v: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let n = T::BITS as usize >> 3;
+        let mut mem = [0u8; 256];
+        v.put(&mut mem[..n]);
+        let mut buf = [0u8; 514];
+        buf[0] = b'0';
+        buf[1] = b'x';
+        for i in 0..n {
+            let e = mem[n - 1 - i];
+            buf[2 + 2 * i] = b"0123456789abcdef"[(e >> 4) as usize];
+            buf[3 + 2 * i] = b"0123456789abcdef"[(e & 0x0f) as usize];
+        }
+        serializer.serialize_str(core::str::from_utf8(&buf[..2 + 2 * n]).unwrap())
+    }
+
+    pub fn deserialize<'de, T: Eint, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(HexVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Serialize as a little-endian fixed-length byte array.
+pub mod le_bytes {
+    use super::*;
+
+    pub fn serialize<T: Eint, S: Serializer>(v: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let n = T::BITS as usize >> 3;
+        let mut mem = [0u8; 256];
+        v.put(&mut mem[..n]);
+        serialize_bytes(&mem[..n], serializer)
+    }
+
+    pub fn deserialize<'de, T: Eint, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_tuple(T::BITS as usize >> 3, BytesVisitor { be: false, ty: core::marker::PhantomData })
+    }
+}
+
+macro_rules! impl_serde {
+    ($name:ty) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    hex::serialize(self, serializer)
+                } else {
+                    le_bytes::serialize(self, serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    hex::deserialize(deserializer)
+                } else {
+                    le_bytes::deserialize(deserializer)
+                }
+            }
+        }
+    };
+}
+
+impl_serde!(E8);
+impl_serde!(E16);
+impl_serde!(E32);
+impl_serde!(E64);
+impl_serde!(E128);
+impl_serde!(E256);
+impl_serde!(E512);
+impl_serde!(E1024);
+impl_serde!(E2048);
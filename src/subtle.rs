@@ -0,0 +1,35 @@
+use crate::*;
+
+macro_rules! impl_subtle {
+    ($name:ty) => {
+        impl ::subtle::ConditionallySelectable for $name {
+            fn conditional_select(a: &Self, b: &Self, choice: ::subtle::Choice) -> Self {
+                <$name as Eint>::ct_select(choice.unwrap_u8(), *b, *a)
+            }
+        }
+
+        impl ::subtle::ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> ::subtle::Choice {
+                ::subtle::Choice::from(Eint::ct_eq(self, other))
+            }
+        }
+
+        impl ::subtle::ConstantTimeGreater for $name {
+            fn ct_gt(&self, other: &Self) -> ::subtle::Choice {
+                ::subtle::Choice::from(other.ct_lt_u(self))
+            }
+        }
+
+        impl ::subtle::ConstantTimeLess for $name {}
+    };
+}
+
+impl_subtle!(E8);
+impl_subtle!(E16);
+impl_subtle!(E32);
+impl_subtle!(E64);
+impl_subtle!(E128);
+impl_subtle!(E256);
+impl_subtle!(E512);
+impl_subtle!(E1024);
+impl_subtle!(E2048);
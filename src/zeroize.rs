@@ -0,0 +1,114 @@
+//! Zeroize support.
+//!
+//! Every integer type implements Zeroize. Since the types are Copy, wiping a value does not wipe the copies made of it;
+//! hold secrets in a Secret instead, which cannot be copied and is wiped on drop.
+
+use crate::*;
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A secret integer. It is not Copy, is zeroized when dropped, and only exposes the constant-time operations.
+pub struct Secret<T: Eint + Zeroize>(T);
+
+impl<T: Eint + Zeroize> Secret<T> {
+    /// Wraps a value. The caller is responsible for wiping the original if it lives elsewhere.
+    pub fn new(v: T) -> Self {
+        Self(v)
+    }
+
+    /// Wrapping (modular) addition in constant time.
+    pub fn ct_add(&self, other: &Self) -> Self {
+        Self(self.0.ct_add(other.0))
+    }
+
+    /// Returns 1 if self equals other, otherwise 0, in constant time.
+    pub fn ct_eq(&self, other: &Self) -> u8 {
+        self.0.ct_eq(&other.0)
+    }
+
+    /// Returns 1 if self is less than other, otherwise 0, in constant time. Signed.
+    pub fn ct_lt_s(&self, other: &Self) -> u8 {
+        self.0.ct_lt_s(&other.0)
+    }
+
+    /// Returns 1 if self is less than other, otherwise 0, in constant time.
+    pub fn ct_lt_u(&self, other: &Self) -> u8 {
+        self.0.ct_lt_u(&other.0)
+    }
+
+    /// Wrapping (modular) multiplication in constant time.
+    pub fn ct_mul(&self, other: &Self) -> Self {
+        Self(self.0.ct_mul(other.0))
+    }
+
+    /// Returns a if choice is 1, or b if choice is 0, in constant time.
+    pub fn ct_select(choice: u8, a: &Self, b: &Self) -> Self {
+        Self(T::ct_select(choice, a.0, b.0))
+    }
+
+    /// Wrapping (modular) subtraction in constant time.
+    pub fn ct_sub(&self, other: &Self) -> Self {
+        Self(self.0.ct_sub(other.0))
+    }
+
+    /// Swaps self and other if choice is 1, in constant time.
+    pub fn ct_swap(&mut self, other: &mut Self, choice: u8) {
+        self.0.ct_swap(&mut other.0, choice)
+    }
+
+    /// Returns a reference to the secret value.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Eint + Zeroize> Clone for Secret<T> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<T: Eint + Zeroize> core::fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Secret(..)")
+    }
+}
+
+impl<T: Eint + Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Eint + Zeroize> From<T> for Secret<T> {
+    fn from(v: T) -> Self {
+        Self::new(v)
+    }
+}
+
+impl<T: Eint + Zeroize> Zeroize for Secret<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Eint + Zeroize> ZeroizeOnDrop for Secret<T> {}
+
+macro_rules! impl_zeroize {
+    ($name:ty) => {
+        impl Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+    };
+}
+
+impl_zeroize!(E8);
+impl_zeroize!(E16);
+impl_zeroize!(E32);
+impl_zeroize!(E64);
+impl_zeroize!(E128);
+impl_zeroize!(E256);
+impl_zeroize!(E512);
+impl_zeroize!(E1024);
+impl_zeroize!(E2048);
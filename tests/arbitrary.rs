@@ -0,0 +1,38 @@
+#![cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+use eint::{Eint, E16, E2048, E256, E8};
+
+#[test]
+fn test_arbitrary_edge_cases() {
+    let mut seen = [false; 5];
+    for i in 0..=255u8 {
+        let mem = [i, i.wrapping_mul(31), 7, 9, 200, 3];
+        let x = E256::arbitrary(&mut Unstructured::new(&mem)).unwrap();
+        for (j, e) in [E256::ZERO, E256::ONE, E256::MAX_U, E256::MIN_S, E256::MAX_S].iter().enumerate() {
+            seen[j] |= x == *e;
+        }
+        E8::arbitrary(&mut Unstructured::new(&mem)).unwrap();
+        E2048::arbitrary(&mut Unstructured::new(&mem)).unwrap();
+    }
+    assert!(seen.iter().all(|e| *e));
+}
+
+fn check_size_hint<T: Eint + for<'a> Arbitrary<'a>>() {
+    let (lo, hi) = T::size_hint(0);
+    let hi = hi.unwrap();
+    for i in 0..=255u8 {
+        let mem = [[i, 0xff, 0xff, 0xff].as_slice(), &[0xa5; 256]].concat();
+        let mut u = Unstructured::new(&mem);
+        T::arbitrary(&mut u).unwrap();
+        let used = mem.len() - u.len();
+        assert!(lo <= used && used <= hi);
+    }
+}
+
+#[test]
+fn test_arbitrary_size_hint() {
+    check_size_hint::<E8>();
+    check_size_hint::<E16>();
+    check_size_hint::<E256>();
+    check_size_hint::<E2048>();
+}
@@ -29,6 +29,17 @@ fn test_lo_sext_bug_0() {
     assert_eq!(E512::from(x).lo_sext(), y);
 }
 
+#[test]
+fn test_from_prim_bug_0() {
+    assert_eq!(E256::from(u8::MAX), E256([0xff, 0, 0, 0]));
+    assert_eq!(E256::from(0x0123_4567_89ab_cdefu64), E256([0x0123_4567_89ab_cdef, 0, 0, 0]));
+    assert_eq!(E512::from(u128::MAX), E512([u64::MAX, u64::MAX, 0, 0, 0, 0, 0, 0]));
+    assert_eq!(E2048::from(-1i8), E2048::MAX_U);
+    assert_eq!(E256::from(-2i16), E256([u64::MAX - 1, u64::MAX, u64::MAX, u64::MAX]));
+    assert_eq!(E256::from(i128::MIN), E256([0, 1 << 63, u64::MAX, u64::MAX]));
+    assert_eq!(E1024::from(i32::MAX), E1024::from(0x7fff_ffffu32));
+}
+
 #[test]
 fn test_lowhex_bug_0() {
     let x = E64(0x000000ffffffff12);
@@ -43,7 +54,7 @@ fn test_overflowing_mul_s_bug_0() {
     let y: u64 = 0xffffffffffffff00;
     let (r0, b0) = Eint::overflowing_mul_s(E64::from(x), E64::from(y));
     let (r1, b1) = (x as i64).overflowing_mul(y as i64);
-    assert_eq!(r0, r1.into());
+    assert_eq!(r0, E64::from(r1));
     assert_eq!(r0, E64(r1 as u64));
     assert_eq!(b0, b1);
 }
@@ -54,7 +65,7 @@ fn test_overflowing_mul_s_bug_1() {
     let y: u64 = 0xffffff12;
     let (r0, b0) = Eint::overflowing_mul_s(E64::from(x), E64::from(y));
     let (r1, b1) = (x as i64).overflowing_mul(y as i64);
-    assert_eq!(r0, r1.into());
+    assert_eq!(r0, E64::from(r1));
     assert_eq!(b0, b1);
 }
 
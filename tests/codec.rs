@@ -0,0 +1,32 @@
+#![cfg(any(feature = "borsh", feature = "scale"))]
+use eint::*;
+
+#[cfg(feature = "borsh")]
+#[test]
+fn test_codec_borsh() {
+    let x = E256([u64::MAX, 0, 0, 0x00ff000000000000]);
+    let b = borsh::to_vec(&x).unwrap();
+    assert_eq!(b[..8], [0xff; 8]);
+    assert_eq!(b[8..30], [0; 22]);
+    assert_eq!(b[30..], [0xff, 0x00]);
+    assert_eq!(borsh::from_slice::<E256>(&b).unwrap(), x);
+    assert!(borsh::from_slice::<E256>(&b[..31]).is_err());
+    assert_eq!(borsh::to_vec(&E16(0x1234)).unwrap(), [0x34, 0x12]);
+    assert_eq!(borsh::from_slice::<E2048>(&[0xff; 256]).unwrap(), E2048::MAX_U);
+}
+
+#[cfg(feature = "scale")]
+#[test]
+fn test_codec_scale() {
+    use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+    let x = E128(0x0123456789abcdef0011223344556677);
+    assert_eq!(x.encode(), 0x0123456789abcdef0011223344556677u128.encode());
+    assert_eq!(E128::decode(&mut &x.encode()[..]).unwrap(), x);
+    let x = E256::MIN_S;
+    let b = x.encode();
+    assert_eq!(b.len(), E256::max_encoded_len());
+    assert_eq!(b[31], 0x80);
+    assert_eq!(E256::decode(&mut &b[..]).unwrap(), x);
+    assert!(E256::decode(&mut &b[..31]).is_err());
+    assert_eq!(E2048::max_encoded_len(), 256);
+}
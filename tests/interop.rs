@@ -0,0 +1,51 @@
+#![cfg(any(feature = "ethnum", feature = "primitive-types", feature = "ruint"))]
+
+use eint::*;
+
+#[cfg(feature = "ethnum")]
+#[test]
+fn test_interop_ethnum() {
+    let x = E256([u64::MAX, 0, 1, 0x8000000000000000]);
+    let u = ethnum::U256::from(x);
+    assert_eq!(u.into_words(), (0x8000000000000000_0000000000000001, 0x0000000000000000_ffffffffffffffff));
+    assert_eq!(E256::from(u), x);
+    assert_eq!(ethnum::I256::from(E256::MAX_U), ethnum::I256::from(-1i32));
+    assert_eq!(E256::from(ethnum::I256::MIN), E256::MIN_S);
+    assert_eq!(ethnum::U256::try_from(E512::from(x)), Ok(u));
+    assert_eq!(ethnum::U256::try_from(E2048::from(x)), Ok(u));
+    assert!(ethnum::U256::try_from(E1024::MAX_U).is_err());
+    assert_eq!(ethnum::I256::try_from(E512::MAX_U), Ok(ethnum::I256::from(-1i32)));
+    assert_eq!(ethnum::I256::try_from(E2048::from(E256::MIN_S).sext(255)), Ok(ethnum::I256::MIN));
+    assert!(ethnum::I256::try_from(E512::from(x)).is_err());
+    assert!(ethnum::I256::try_from(E1024::MIN_S).is_err());
+}
+
+#[cfg(feature = "primitive-types")]
+#[test]
+fn test_interop_primitive_types() {
+    let x = E256([u64::MAX, 0, 1, 0x8000000000000000]);
+    let u = primitive_types::U256::from(x);
+    assert_eq!(u.low_u64(), u64::MAX);
+    assert!(u.bit(255));
+    assert_eq!(E256::from(u), x);
+    assert_eq!(E256::try_from(primitive_types::U512::from(x)), Ok(x));
+    assert_eq!(primitive_types::U256::try_from(E512::from(x)), Ok(u));
+    assert!(primitive_types::U256::try_from(E512::MAX_U).is_err());
+    assert!(E256::try_from(primitive_types::U512::MAX).is_err());
+    assert_eq!(E512::from(primitive_types::U512::MAX), E512::MAX_U);
+}
+
+#[cfg(feature = "ruint")]
+#[test]
+fn test_interop_ruint() {
+    let x = E256([u64::MAX, 0, 1, 0x8000000000000000]);
+    let u: ruint::aliases::U256 = x.into();
+    assert_eq!(u.as_limbs(), &x.0);
+    assert_eq!(E256::from(u), x);
+    assert_eq!(E2048::from(ruint::Uint::<2048, 32>::MAX), E2048::MAX_U);
+    assert_eq!(ruint::aliases::U256::try_from(E512::from(x)), Ok(u));
+    assert_eq!(ruint::aliases::U256::try_from(E2048::from(x)), Ok(u));
+    assert_eq!(ruint::aliases::U512::try_from(E1024::from(E512::MAX_U)), Ok(ruint::aliases::U512::MAX));
+    assert!(ruint::aliases::U256::try_from(E512::MAX_U).is_err());
+    assert!(ruint::Uint::<1024, 16>::try_from(E2048::MAX_U).is_err());
+}
@@ -0,0 +1,38 @@
+use eint::*;
+
+#[test]
+fn test_layout() {
+    assert_eq!(core::mem::size_of::<E8>(), 1);
+    assert_eq!(core::mem::align_of::<E128>(), core::mem::align_of::<u128>());
+    assert_eq!(core::mem::size_of::<E256>(), 32);
+    assert_eq!(core::mem::align_of::<E256>(), core::mem::align_of::<u64>());
+    assert_eq!(core::mem::size_of::<E2048>(), 256);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_layout_bytemuck() {
+    let mem = [0x0123456789abcdefu64, 1, 2, 3, 4, 5, 6, 7];
+    let b: &[u8] = bytemuck::cast_slice(&mem);
+    let e: &[E256] = bytemuck::cast_slice(b);
+    assert_eq!(e, &[E256([0x0123456789abcdef, 1, 2, 3]), E256([4, 5, 6, 7])]);
+    assert!(bytemuck::try_cast_slice::<u8, E256>(&b[1..33]).is_err());
+    assert!(bytemuck::try_cast_slice::<u8, E256>(&b[..40]).is_err());
+    let mut x = [E64(0); 2];
+    bytemuck::cast_slice_mut::<E64, u8>(&mut x)[8] = 0x42;
+    assert_eq!(x[1], E64(u64::from_ne_bytes([0x42, 0, 0, 0, 0, 0, 0, 0])));
+    assert_eq!(<E2048 as bytemuck::Zeroable>::zeroed(), E2048::ZERO);
+}
+
+#[cfg(feature = "zerocopy")]
+#[test]
+fn test_layout_zerocopy() {
+    use zerocopy::{AsBytes, FromBytes};
+    let mem = [0x0123456789abcdefu64, 1, 2, 3, 4, 5, 6, 7];
+    let b = mem.as_bytes();
+    let e = E256::slice_from(b).unwrap();
+    assert_eq!(e, &[E256([0x0123456789abcdef, 1, 2, 3]), E256([4, 5, 6, 7])]);
+    assert!(E256::slice_from(&b[1..33]).is_none());
+    assert_eq!(E256([0x0123456789abcdef, 1, 2, 3]).as_bytes(), &b[..32]);
+    assert_eq!(E8::read_from(&[0x42u8][..]), Some(E8(0x42)));
+}
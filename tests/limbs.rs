@@ -0,0 +1,125 @@
+use eint::*;
+
+fn check_lanes<T: Eint>(x: T) {
+    let mut mem = [0u8; 256];
+    let n = T::BITS as usize >> 3;
+    x.put(&mut mem[..n]);
+    for (i, e) in x.lanes::<u8>().enumerate() {
+        assert_eq!(e, mem[i]);
+    }
+    assert_eq!(x.lanes::<u8>().len(), n);
+    if T::BITS >= 16 {
+        for (i, e) in x.lanes::<u16>().enumerate() {
+            assert_eq!(e, u16::from_le_bytes([mem[i * 2], mem[i * 2 + 1]]));
+        }
+    }
+    let mut y = T::ZERO;
+    for (i, e) in x.lanes::<u8>().enumerate() {
+        y.set_lane(i, e);
+    }
+    assert_eq!(x, y);
+    let limbs: [u64; 32] = core::array::from_fn(|i| if i < T::LIMBS { x.limb(i) } else { 0 });
+    assert_eq!(T::from_limbs(&limbs), x);
+}
+
+#[test]
+fn test_lanes() {
+    check_lanes(E8(0xa5));
+    check_lanes(E16(0x1234));
+    check_lanes(E32::MAX_S);
+    check_lanes(E64(0x0123_4567_89ab_cdef));
+    check_lanes(E128::MAX_U - E128::from(12345u32));
+    check_lanes(E256([1, 2, 3, u64::MAX]));
+    check_lanes(E2048::MIN_S | E2048::from(0xdead_beefu32));
+}
+
+#[test]
+fn test_lane() {
+    let mut x = E256::ZERO;
+    x.set_lane::<u32>(3, 0xdead_beef);
+    assert_eq!(x, E256::from(0xdead_beefu32) << 96);
+    assert_eq!(x.lane::<u32>(3), 0xdead_beef);
+    assert_eq!(x.lane::<u16>(7), 0xdead);
+    assert_eq!(x.lane::<u64>(1), 0xdead_beef_0000_0000);
+    x.set_lane::<u16>(6, 0x1234);
+    assert_eq!(x.lane::<u32>(3), 0xdead_1234);
+    assert_eq!(E128::MAX_U.lanes::<u32>().rev().count(), 4);
+    assert_eq!(E16(0x1234).lanes::<u8>().rev().collect::<Vec<_>>(), [0x12, 0x34]);
+}
+
+#[test]
+#[should_panic]
+fn test_lane_out_of_bounds() {
+    E32(0).lane::<u16>(2);
+}
+
+#[test]
+fn test_limbs() {
+    assert_eq!(E8::LIMBS, 1);
+    assert_eq!(E128::LIMBS, 2);
+    assert_eq!(E2048::LIMBS, 32);
+    assert_eq!(E8::from_limbs(&[0x1ff]), E8(0xff));
+    assert_eq!(E128::from_limbs(&[1, 2, 3]), E128(2 << 64 | 1));
+    assert_eq!(E256::from_limbs(&[1]), E256::ONE);
+    assert_eq!(E128(2 << 64 | 1).limb(1), 2);
+    let mut x = E16(0x1234);
+    x.set_limb(0, 0xffff_5678);
+    assert_eq!(x, E16(0x5678));
+    let mut y = E256::ZERO;
+    y.limbs_mut()[2] = 7;
+    assert_eq!(y.limbs()[..], [0, 0, 7, 0]);
+    assert_eq!(E2048::MAX_U.limbs()[..], [u64::MAX; 32]);
+    assert_eq!(E8(5).limbs()[..], [5]);
+    assert_eq!(E128(2 << 64 | 1).limbs()[..], [1, 2]);
+    let mut w = E8(5);
+    w.limbs_mut()[0] = 0x1ff;
+    assert_eq!(w, E8(0xff));
+    let mut v = E128::ZERO;
+    v.limbs_mut().copy_from_slice(&[3, 4]);
+    assert_eq!(v, E128(4 << 64 | 3));
+    let mut z = E128(2 << 64 | 1);
+    assert_eq!((z.limb(0), z.limb(1)), (1, 2));
+    z.set_limb(1, 3);
+    assert_eq!(z, E128(3 << 64 | 1));
+}
+
+#[test]
+fn test_lanes_swar() {
+    assert_eq!(E16(0xff01).lanes_add::<E8>(E16(0x02ff)), E16(0x0100));
+    assert_eq!(E16(0x0100).lanes_sub::<u8>(E16(0x0101)), E16(0x00ff));
+    assert_eq!(E8(0x80).lanes_max_s::<E8>(E8(0x7f)), E8(0x7f));
+    assert_eq!(E8(0x80).lanes_max_u::<E8>(E8(0x7f)), E8(0x80));
+    assert_eq!(E32(0x8000_0001).lanes_min_s::<E16>(E32(0x0001_8000)), E32(0x8000_8000));
+    assert_eq!(E32(0x8000_0001).lanes_min_u::<E16>(E32(0x0001_8000)), E32(0x0001_0001));
+    assert_eq!(E32(0x0102_0304).lanes_cmp_eq::<E8>(E32(0x0100_0304)), E32(0b1011));
+    assert_eq!(E32(0x8181_8181).lanes_shl::<E8>(9), E32(0x0202_0202));
+    let x = E128::MAX_U.lanes_add::<E64>(E128::ONE);
+    assert_eq!(x, E128::MAX_U << 64);
+    let y = E2048::MAX_U.lanes_add::<E32>(E2048::ONE);
+    assert_eq!(y.lanes::<u32>().filter(|e| *e == 0).count(), 1);
+}
+
+#[test]
+fn test_lanes_cmp_eq() {
+    let x = E256([0x8000_0000_0000_0001, 0, u64::MAX, 0x00ff_0000_0000_0000]);
+    let y = E256([0x8000_0000_0000_0000, 0, u64::MAX, 0x0000_0000_0000_0000]);
+    assert_eq!(x.lanes_cmp_eq::<u8>(y), E256::from(0xbfff_fffeu32));
+    assert_eq!(x.lanes_cmp_eq::<u16>(y), E256::from(0x7ffeu32));
+    assert_eq!(x.lanes_cmp_eq::<u32>(y), E256::from(0x7eu32));
+    assert_eq!(x.lanes_cmp_eq::<u64>(y), E256::from(0x6u32));
+    assert_eq!(E2048::ZERO.lanes_cmp_eq::<u8>(E2048::ZERO), E2048::from_limbs(&[u64::MAX; 4]));
+    assert_eq!(E64(0x1122).lanes_cmp_eq::<E64>(E64(0x1122)), E64(1));
+    assert_eq!(E8(0x12).lanes_cmp_eq::<u8>(E8(0x13)), E8(0));
+}
+
+#[test]
+#[should_panic]
+fn test_lanes_wider_than_integer() {
+    E16(0).lanes_add::<E32>(E16(0));
+}
+
+#[test]
+#[should_panic(expected = "lane wider than integer")]
+fn test_lanes_iter_wider_than_integer() {
+    E16(0).lanes::<u32>();
+}
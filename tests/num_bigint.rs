@@ -0,0 +1,35 @@
+#![cfg(feature = "num-bigint")]
+use eint::{Eint, E2048, E256, E8};
+use num_bigint::{BigInt, BigUint};
+
+#[test]
+fn test_num_bigint_biguint() {
+    let x = E256([0, 0, 1, 0]);
+    let u = BigUint::from(x);
+    assert_eq!(u.to_u64_digits(), [0, 0, 1]);
+    assert_eq!(E256::try_from(&u), Ok(x));
+    let u = BigUint::from(E256::MIN_S);
+    assert_eq!(u, BigUint::from(1u8) << 255u32);
+    assert_eq!(E256::try_from(&u), Ok(E256::MIN_S));
+    assert_eq!(BigUint::from(E2048::MAX_U), (BigUint::from(1u8) << 2048u32) - 1u8);
+    assert_eq!(E2048::try_from(&BigUint::from(E2048::MAX_U)), Ok(E2048::MAX_U));
+    assert!(E2048::try_from(&(BigUint::from(1u8) << 2048u32)).is_err());
+    assert_eq!(E8::try_from(&BigUint::from(255u8)), Ok(E8(255)));
+    assert!(E8::try_from(&BigUint::from(256u16)).is_err());
+}
+
+#[test]
+fn test_num_bigint_bigint() {
+    assert_eq!(E8(0x80).to_bigint_s(), BigInt::from(-128));
+    assert_eq!(E8(0x7f).to_bigint_s(), BigInt::from(127));
+    assert_eq!(E256::MAX_U.to_bigint_s(), BigInt::from(-1));
+    assert_eq!(E256::MIN_S.to_bigint_s(), -(BigInt::from(1u8) << 255u32));
+    assert_eq!(E8::try_from(&BigInt::from(-128)), Ok(E8(0x80)));
+    assert_eq!(E8::try_from(&BigInt::from(127)), Ok(E8(0x7f)));
+    assert!(E8::try_from(&BigInt::from(-129)).is_err());
+    assert!(E8::try_from(&BigInt::from(128)).is_err());
+    for x in [E2048::MIN_S, E2048::MAX_S, E2048::MAX_U, E2048::ZERO, E2048::ONE] {
+        assert_eq!(E2048::try_from(&x.to_bigint_s()), Ok(x));
+    }
+    assert!(E2048::try_from(&(BigInt::from(1u8) << 2047u32)).is_err());
+}
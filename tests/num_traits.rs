@@ -0,0 +1,67 @@
+#![cfg(feature = "num-traits")]
+use eint::{Eint, ParseEintError, E128, E2048, E256, E64, E8};
+use num_traits::{Bounded, CheckedMul, CheckedNeg, Num, One, PrimInt, ToPrimitive, WrappingAdd, Zero};
+
+fn sum_of_squares<T: PrimInt + WrappingAdd>(n: u32) -> T {
+    let mut r = T::zero();
+    for i in 1..=n {
+        let i: T = num_traits::cast(i).unwrap();
+        r = r.wrapping_add(&(i * i));
+    }
+    r
+}
+
+#[test]
+fn test_num_traits_generic() {
+    assert_eq!(sum_of_squares::<E64>(100), E64(338350));
+    assert_eq!(sum_of_squares::<E256>(100), E256::from(338350u32));
+    assert_eq!(sum_of_squares::<E8>(10), E8((385 % 256) as u8));
+    assert_eq!(<E256 as Bounded>::max_value(), E256::MAX_U);
+    assert_eq!(<E2048 as Zero>::zero(), E2048::ZERO);
+    assert_eq!(<E2048 as One>::one(), E2048::ONE);
+    assert_eq!(E256::MAX_U.checked_mul(&E256::from(2u8)), None);
+    assert_eq!(E256::from(3u8).pow(161).count_ones(), E256::from(3u8).pow(161).cpop());
+    assert_eq!(PrimInt::leading_zeros(E256::ONE), 255);
+    assert_eq!(E256::ZERO.checked_neg(), Some(E256::ZERO));
+    assert_eq!(E256::ONE.checked_neg(), None);
+    assert_eq!(E8(0x80).checked_neg(), None);
+}
+
+#[test]
+fn test_num_traits_byte_order() {
+    let x = 0x0123456789abcdef_fedcba9876543210u128;
+    assert_eq!(PrimInt::to_be(E128(x)), E128(x.to_be()));
+    assert_eq!(PrimInt::to_le(E128(x)), E128(x.to_le()));
+    assert_eq!(<E128 as PrimInt>::from_be(E128(x)), E128(u128::from_be(x)));
+    assert_eq!(<E128 as PrimInt>::from_le(E128(x)), E128(u128::from_le(x)));
+    assert_eq!(PrimInt::to_be(E64(x as u64)), E64((x as u64).to_be()));
+    let y = E256::from(x) << 64 | E256::from(0x55u8);
+    assert_eq!(<E256 as PrimInt>::from_be(PrimInt::to_be(y)), y);
+    assert_eq!(<E256 as PrimInt>::from_le(PrimInt::to_le(y)), y);
+    let mut be = [0u8; 32];
+    y.put(&mut be);
+    be.reverse();
+    let mem: Vec<u8> = PrimInt::to_be(y).0.iter().flat_map(|e| e.to_ne_bytes()).collect();
+    assert_eq!(mem, be);
+}
+
+#[test]
+fn test_num_traits_cast() {
+    assert_eq!(<E8 as num_traits::NumCast>::from(255u32), Some(E8(255)));
+    assert_eq!(<E8 as num_traits::NumCast>::from(256u32), None);
+    assert_eq!(<E256 as num_traits::NumCast>::from(-1i32), None);
+    assert_eq!(<E256 as num_traits::NumCast>::from(u128::MAX), Some(E256::from(u128::MAX)));
+    assert_eq!(E256::from(u128::MAX).to_u128(), Some(u128::MAX));
+    assert_eq!((E256::from(u128::MAX) + E256::ONE).to_u128(), None);
+    assert_eq!(E64::MAX_U.to_i64(), None);
+    assert_eq!(E64::MAX_S.to_i64(), Some(i64::MAX));
+}
+
+#[test]
+fn test_num_traits_from_str_radix() {
+    assert_eq!(E256::from_str_radix("ffffffffffffffffffffffffffffffff", 16), Ok(E256::from(u128::MAX)));
+    assert_eq!(E8::from_str_radix("255", 10), Ok(E8(255)));
+    assert_eq!(E8::from_str_radix("256", 10), Err(ParseEintError::Overflow));
+    assert_eq!(E8::from_str_radix("", 10), Err(ParseEintError::Empty));
+    assert_eq!(E8::from_str_radix("1z", 10), Err(ParseEintError::InvalidDigit));
+}
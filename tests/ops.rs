@@ -0,0 +1,106 @@
+#![allow(clippy::op_ref)]
+
+use eint::*;
+
+fn check_ct_arith<T: Eint>(values: &[T]) {
+    for x in values {
+        for y in values {
+            assert_eq!(x.ct_add(*y), x.wrapping_add(*y));
+            assert_eq!(x.ct_sub(*y), x.wrapping_sub(*y));
+            assert_eq!(x.ct_mul(*y), x.wrapping_mul(*y));
+        }
+    }
+}
+
+#[test]
+fn test_ops_ct_arith() {
+    check_ct_arith(&[E8(0), E8(1), E8(0x7f), E8(0x80), E8(0xff), E8(0x5a)]);
+    check_ct_arith(&[
+        E128::ZERO,
+        E128::ONE,
+        E128::MAX_U,
+        E128::MIN_S,
+        E128::from(u64::MAX),
+        E128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210),
+    ]);
+    check_ct_arith(&[
+        E2048::ZERO,
+        E2048::ONE,
+        E2048::MAX_U,
+        E2048::MIN_S,
+        E2048::from(u64::MAX),
+        E2048::MAX_U >> 1000,
+        E2048::from(0xdead_beef_u32) << 1023 | E2048::from(u128::MAX),
+    ]);
+}
+
+#[test]
+fn test_ops_prim() {
+    let x = E256::from(41u8);
+    assert_eq!(x + 1u64, E256::from(42u8));
+    assert_eq!(x + 1, E256::from(42u8));
+    assert_eq!(x + -1i8, E256::from(40u8));
+    assert_eq!(E256::ZERO - 1u8, E256::MAX_U);
+    assert_eq!(x * 2u128 / 3u32 % 5u16, E256::from(2u8));
+    assert_eq!(x & 1u8 | 2u8 ^ 4u8, E256::from(7u8));
+    let mut y = E8(250);
+    y += 10u64;
+    assert_eq!(y, E8(4));
+    y -= 5i32;
+    assert_eq!(y, E8(255));
+}
+
+#[test]
+fn test_ops_prim_cmp() {
+    assert!(E256::ZERO == 0u64);
+    assert!(0u64 == E256::ZERO);
+    assert!(E256::from(7u8) > 6u8);
+    assert!(5u128 < E256::from(7u8));
+    assert!(E256::MAX_U > u128::MAX);
+    assert!(E8(0) != 256u64);
+    assert!(E8(255) < 256u16);
+    assert!(E8(255) == 255u128);
+    assert!(E64(u64::MAX) == u64::MAX);
+}
+
+#[test]
+fn test_ops_ref() {
+    let a = E2048::MAX_U;
+    let b = E2048::ONE;
+    assert_eq!(&a + &b, E2048::ZERO);
+    assert_eq!(a + &b, E2048::ZERO);
+    assert_eq!(&a - b, a - b);
+    assert_eq!(&a * &a, E2048::ONE);
+    assert_eq!(&a / &b, a);
+    assert_eq!(&a % &b, E2048::ZERO);
+    assert_eq!(&a & &b, b);
+    assert_eq!(&a | &b, a);
+    assert_eq!(&a ^ &b, a - b);
+    assert_eq!(-&b, a);
+    assert_eq!(!&a, E2048::ZERO);
+    let mut c = E2048::ZERO;
+    c += &b;
+    c *= &a;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn test_ops_shift_eint() {
+    let x = E256::ONE;
+    assert_eq!(x << E64(255), E256::MIN_S);
+    assert_eq!(x << E64(256), x);
+    assert_eq!(x << E8(0xff), E256::MIN_S);
+    assert_eq!(E256::MIN_S >> E2048::from(255u8), x);
+    assert_eq!(E64(1) << E256::from(65u8), E64(2));
+    assert_eq!(E8(0x80) >> E8(7), E8(1));
+}
+
+#[test]
+fn test_ops_iter() {
+    let v = [E256::from(1u8), E256::from(2u8), E256::from(3u8), E256::from(4u8)];
+    assert_eq!(v.iter().sum::<E256>(), E256::from(10u8));
+    assert_eq!(v.iter().product::<E256>(), E256::from(24u8));
+    assert_eq!(v.into_iter().sum::<E256>(), E256::from(10u8));
+    assert_eq!([E8(200), E8(100)].into_iter().sum::<E8>(), E8(44));
+    assert_eq!(core::iter::empty::<E64>().product::<E64>(), E64(1));
+}
@@ -0,0 +1,81 @@
+use eint::*;
+
+#[test]
+fn test_get_partial() {
+    let mem = [0x34, 0x82, 0x56];
+    assert_eq!(E8::get_partial(&mem, 1, Extension::Sign), E8(0x34));
+    assert_eq!(E8::get_partial(&mem, 0, Extension::Sign), E8(0));
+    assert_eq!(E32::get_partial(&mem, 2, Extension::Zero), E32(0x8234));
+    assert_eq!(E32::get_partial(&mem, 2, Extension::Sign), E32(0xffff_8234));
+    assert_eq!(E32::get_partial(&mem, 3, Extension::Sign), E32(0x0056_8234));
+    assert_eq!(E2048::get_partial(&mem, 2, Extension::Sign), E2048::from(-0x7dcci32));
+    assert_eq!(E2048::get_partial(&[0xff; 256], 256, Extension::Zero), E2048::MAX_U);
+}
+
+#[test]
+#[should_panic(expected = "partial width out of range")]
+fn test_get_partial_too_wide() {
+    E16::get_partial(&[0; 3], 3, Extension::Zero);
+}
+
+#[test]
+#[should_panic]
+fn test_get_partial_mem_too_short() {
+    E64::get_partial(&[0; 3], 4, Extension::Zero);
+}
+
+#[test]
+fn test_put_partial() {
+    let mut mem = [0xaa; 4];
+    E32(0x1234_5678).put_partial(&mut mem, 3);
+    assert_eq!(mem, [0x78, 0x56, 0x34, 0xaa]);
+    E8(0x11).put_partial(&mut mem[3..], 1);
+    assert_eq!(mem, [0x78, 0x56, 0x34, 0x11]);
+    E256::MAX_U.put_partial(&mut mem, 0);
+    assert_eq!(mem, [0x78, 0x56, 0x34, 0x11]);
+    E64(0x0102_0304_0506_0708).put_partial(&mut mem, 2);
+    assert_eq!(mem, [0x08, 0x07, 0x34, 0x11]);
+}
+
+#[test]
+#[should_panic(expected = "partial width out of range")]
+fn test_put_partial_too_wide() {
+    E8(0).put_partial(&mut [0; 2], 2);
+}
+
+#[test]
+#[should_panic(expected = "partial width out of range")]
+fn test_put_partial_too_wide_e2048() {
+    E2048::MAX_U.put_partial(&mut [0; 257], 257);
+}
+
+#[test]
+fn test_put_masked() {
+    let mut mem = [0xaa; 4];
+    E32(0x1234_5678).put_masked(&mut mem, E32(0b1010));
+    assert_eq!(mem, [0xaa, 0x56, 0xaa, 0x12]);
+    E8(0x11).put_masked(&mut mem, E8(0xfe));
+    assert_eq!(mem, [0xaa, 0x56, 0xaa, 0x12]);
+    E16(0x3344).put_masked(&mut mem, E16(0xfffe));
+    assert_eq!(mem, [0xaa, 0x33, 0xaa, 0x12]);
+    let mut mem = [0u8; 256];
+    E2048::MAX_U.put_masked(&mut mem, E2048::ONE << 200);
+    assert_eq!(mem.iter().position(|e| *e != 0), Some(200));
+    assert_eq!(mem.iter().filter(|e| **e != 0).count(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_put_masked_mem_too_short() {
+    E64(0).put_masked(&mut [0; 7], E64(1));
+}
+
+#[test]
+fn test_put_lo() {
+    let mut mem = [0xaa; 2];
+    // The lower half of E8 is a nibble, so a whole byte is written with the upper nibble cleared.
+    E8(0x5f).put_lo(&mut mem[..1]);
+    assert_eq!(mem, [0x0f, 0xaa]);
+    E32(0x1234_5678).put_lo(&mut mem);
+    assert_eq!(mem, [0x78, 0x56]);
+}
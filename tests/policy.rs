@@ -0,0 +1,63 @@
+use eint::*;
+
+#[test]
+fn test_policy_checked() {
+    let a = Checked::new(E256::MAX_U);
+    let b = Checked::from(E256::ONE);
+    assert!((a + b).is_poisoned());
+    assert_eq!((a - b).get(), Some(E256::MAX_U - E256::ONE));
+    assert!((b - a).is_poisoned());
+    assert!((a * a).is_poisoned());
+    assert_eq!((a * b).get(), Some(E256::MAX_U));
+    assert!((a / Checked::new(E256::ZERO)).is_poisoned());
+    assert_eq!((a / b).get(), Some(E256::MAX_U));
+    assert!((b << 256).is_poisoned());
+    assert_eq!((b << 255).get(), Some(E256::MIN_S));
+    assert!((a >> 300).is_poisoned());
+    let mut x = Checked::new(E8(200));
+    x += Checked::new(E8(100));
+    assert_eq!(x.get(), None);
+    x -= Checked::new(E8(44));
+    assert!(x.is_poisoned());
+    assert_eq!(format!("{:?}", x), "Checked(poisoned)");
+    assert_eq!(format!("{:?}", b), "Checked(0000000000000000000000000000000000000000000000000000000000000001)");
+    assert!((b + (b - a)).is_poisoned());
+    assert_eq!(a + b, b - a);
+    assert_ne!(a + b, Checked::new(E256::ZERO));
+    assert_ne!(Checked::new(E256::ZERO), a + b);
+    assert_eq!(a - b, Checked::new(E256::MAX_U - E256::ONE));
+}
+
+#[test]
+fn test_policy_saturating() {
+    let a = Saturating(E256::MAX_U);
+    let b = Saturating(E256::ONE);
+    assert_eq!(a + b, a);
+    assert_eq!(b - a, Saturating(E256::ZERO));
+    assert_eq!(a * a, a);
+    assert_eq!(Saturating(E64(3)) * Saturating(E64(5)), Saturating(E64(15)));
+    assert_eq!(a / Saturating(E256::ZERO), a);
+    assert_eq!(b << 255, Saturating(E256::MIN_S));
+    assert_eq!(b << 256, a);
+    assert_eq!(Saturating(E8(0x40)) << 2, Saturating(E8(0xff)));
+    assert_eq!(Saturating(E8(0x40)) << 1, Saturating(E8(0x80)));
+    assert_eq!(Saturating(E8(0)) << 100, Saturating(E8(0)));
+    assert_eq!(a >> 256, Saturating(E256::ZERO));
+    assert_eq!(a >> 255, b);
+    let mut x = Saturating(E16(65000));
+    x += Saturating(E16(1000));
+    assert_eq!(x, Saturating(E16::MAX_U));
+    x -= Saturating(E16(65535));
+    x -= Saturating(E16(1));
+    assert_eq!(x.0, E16(0));
+    let mut x = Saturating(E8(0x40));
+    x <<= 1;
+    assert_eq!(x, Saturating(E8(0x80)));
+    x <<= 1;
+    assert_eq!(x, Saturating(E8(0xff)));
+    x >>= 4;
+    assert_eq!(x, Saturating(E8(0x0f)));
+    x >>= 8;
+    assert_eq!(x, Saturating(E8(0)));
+    assert!(Saturating(E256::MAX_U) > Saturating(E256::ZERO));
+}
@@ -0,0 +1,15 @@
+#![cfg(feature = "proptest")]
+use eint::{Eint, E256, E8};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_proptest_add_sub(x: E256, y: E256) {
+        prop_assert_eq!(x.wrapping_add(y).wrapping_sub(y), x);
+    }
+
+    #[test]
+    fn test_proptest_e8(x: E8, y: E8) {
+        prop_assert_eq!(x.wrapping_mul(y), E8(x.0.wrapping_mul(y.0)));
+    }
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "rand")]
+use eint::{Eint, E2048, E256, E8};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[test]
+fn test_rand_gen_range() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let lo = E256::from(u128::MAX);
+    let hi = lo + E256::from(10u8);
+    for _ in 0..1000 {
+        let x = rng.gen_range(lo..hi);
+        assert!(x >= lo && x < hi);
+    }
+    for _ in 0..1000 {
+        let x = rng.gen_range(E8(250)..=E8(255));
+        assert!(x >= E8(250));
+    }
+    let x: E2048 = rng.gen_range(E2048::MIN_U..=E2048::MAX_U);
+    assert_ne!(x, E2048::MIN_U);
+    let x: E2048 = rng.gen();
+    assert_ne!(x, E2048::MIN_U);
+}
+
+#[test]
+fn test_rand_random_below() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut hits = [0u32; 6];
+    for _ in 0..6000 {
+        let x = eint::rand::random_below(&mut rng, E256::from(6u8));
+        hits[x.u64() as usize] += 1;
+    }
+    assert!(hits.iter().all(|&e| e > 800 && e < 1200));
+    let bound = E256::MIN_S + E256::ONE;
+    for _ in 0..100 {
+        assert!(eint::rand::random_below(&mut rng, bound) < bound);
+    }
+    for _ in 0..100 {
+        let x: E256 = eint::rand::random_bits(&mut rng, 130);
+        assert!(x.clz() >= 126);
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "serde")]
+use eint::{Eint, E2048, E256, E32, E8};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn test_serde_hex() {
+    let x = E256([u64::MAX, 0, 0, 1]);
+    let s = serde_json::to_string(&x).unwrap();
+    assert_eq!(s, "\"0x000000000000000100000000000000000000000000000000ffffffffffffffff\"");
+    assert_eq!(serde_json::from_str::<E256>(&s).unwrap(), x);
+    let s = serde_json::to_string(&E256::MIN_S).unwrap();
+    assert_eq!(s, "\"0x8000000000000000000000000000000000000000000000000000000000000000\"");
+    assert_eq!(serde_json::from_str::<E256>(&s).unwrap(), E256::MIN_S);
+    assert_eq!(serde_json::from_str::<E256>("\"0x923B3\"").unwrap(), E256::from(0x923b3u32));
+    assert_eq!(serde_json::to_string(&E8(0x0f)).unwrap(), "\"0x0f\"");
+    assert!(serde_json::from_str::<E8>("\"0x100\"").is_err());
+    assert!(serde_json::from_str::<E8>("\"0x\"").is_err());
+    assert!(serde_json::from_str::<E8>("\"ff\"").is_err());
+    assert!(serde_json::from_str::<E8>("\"0xfg\"").is_err());
+}
+
+#[test]
+fn test_serde_bytes() {
+    let x = E256::MIN_S;
+    let b = bincode::serialize(&x).unwrap();
+    assert_eq!(b[..31], [0u8; 31]);
+    assert_eq!(b[31], 0x80);
+    assert_eq!(bincode::deserialize::<E256>(&b).unwrap(), x);
+    let x = E2048::MAX_S;
+    assert_eq!(bincode::deserialize::<E2048>(&bincode::serialize(&x).unwrap()).unwrap(), x);
+    assert!(bincode::deserialize::<E256>(&b[..31]).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Account {
+    #[serde(with = "eint::serde::decimal")]
+    balance: E256,
+    #[serde(with = "eint::serde::decimal")]
+    nonce: E8,
+    #[serde(with = "eint::serde::be_bytes")]
+    key: E32,
+}
+
+#[test]
+fn test_serde_with() {
+    let a = Account { balance: E256::MAX_U, nonce: E8(0), key: E32(0x01020304) };
+    let s = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        s,
+        r#"{"balance":"115792089237316195423570985008687907853269984665640564039457584007913129639935","nonce":"0","key":[1,2,3,4]}"#
+    );
+    assert_eq!(serde_json::from_str::<Account>(&s).unwrap(), a);
+    assert!(serde_json::from_str::<Account>(r#"{"balance":"0","nonce":"256","key":[1,2,3,4]}"#).is_err());
+    assert!(serde_json::from_str::<Account>(r#"{"balance":"-1","nonce":"0","key":[1,2,3,4]}"#).is_err());
+    let a = Account { balance: E256::from(10_000_000_000_000_000_000u64), nonce: E8(255), key: E32(0) };
+    let s = serde_json::to_string(&a).unwrap();
+    assert!(s.contains(r#""10000000000000000000""#));
+    assert_eq!(serde_json::from_str::<Account>(&s).unwrap(), a);
+    assert_eq!(bincode::deserialize::<Account>(&bincode::serialize(&a).unwrap()).unwrap(), a);
+}
@@ -0,0 +1,56 @@
+use eint::*;
+
+#[test]
+fn test_signed_cmp() {
+    assert!(I256::from(-1) < I256::from(0));
+    assert!(I256::MIN < I256::MAX);
+    assert!(I8::from(-128) < I8::from(127));
+    assert_eq!(I2048::from(-3i64).max(I2048::from(2)), I2048::from(2));
+    assert!(E256::MAX_U > E256::ZERO);
+    assert!(I256::from(E256::MAX_U) < I256::ZERO);
+}
+
+#[test]
+fn test_signed_conv() {
+    let x = E256([0xb12f7788023e73f4, 0xe2aaa5a70e8d29d2, 0x01f281f891d2d8b6, 0xf0000000000923b3]);
+    assert_eq!(E256::from(I256::from(x)), x);
+    assert_eq!(I512::from(-1i8).0, E512::MAX_U);
+    assert_eq!(I8::try_from(-128i32), Ok(I8::MIN));
+    assert_eq!(I8::try_from(127i128), Ok(I8::MAX));
+    assert!(I8::try_from(300i32).is_err());
+    assert!(I8::try_from(-129i16).is_err());
+    assert!(I64::try_from(i128::MIN).is_err());
+    assert_eq!(I64::try_from(i64::MIN as i128), Ok(I64::MIN));
+    assert_eq!(I256::MIN.unsigned_abs(), E256::MIN_S);
+    assert_eq!(I256::from(-5).unsigned_abs(), E256::from(5));
+}
+
+#[test]
+fn test_signed_fmt() {
+    assert_eq!(format!("{}", I8::from(-128)), "-128");
+    assert_eq!(format!("{}", I64::from(i64::MAX)), "9223372036854775807");
+    assert_eq!(format!("{:>6}", I256::from(-42)), "   -42");
+    assert_eq!(format!("{:+}", I256::from(42)), "+42");
+    assert_eq!(format!("{:?}", I256::ZERO), "0");
+    assert_eq!(
+        format!("{}", I256::MIN),
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+    );
+    assert_eq!(format!("{:x}", I8::from(-1)), "ff");
+}
+
+#[test]
+fn test_signed_ops() {
+    assert_eq!(I256::from(-7) / I256::from(2), I256::from(-3));
+    assert_eq!(I256::from(-7) % I256::from(2), I256::from(-1));
+    assert_eq!(I256::MIN / I256::from(-1), I256::MIN);
+    assert_eq!(I1024::from(-16) >> 2, I1024::from(-4));
+    assert_eq!(I1024::from(-4) << 2, I1024::from(-16));
+    assert_eq!(I32::from(-3) * I32::from(5) + I32::from(1) - I32::from(2), I32::from(-16));
+    assert_eq!(-I16::from(5i16), I16::from(-5i16));
+    assert_eq!(!I16::ZERO, I16::from(-1i16));
+    let mut x = I128::from(-100);
+    x /= I128::from(7);
+    x %= I128::from(4);
+    assert_eq!(x, I128::from(-2));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "subtle")]
+use eint::*;
+use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+#[test]
+fn test_subtle() {
+    let x = E256([u64::MAX, u64::MAX, u64::MAX, 1]);
+    let y = E256([u64::MAX, u64::MAX, u64::MAX, 0]);
+    assert!(bool::from(ConstantTimeEq::ct_eq(&x, &x)));
+    assert!(!bool::from(ConstantTimeEq::ct_eq(&x, &y)));
+    assert_eq!(Eint::ct_eq(&x, &y), 0);
+    assert!(bool::from(x.ct_gt(&y)));
+    assert!(bool::from(y.ct_lt(&x)));
+    assert!(bool::from(E256([0, 1, 0, 0]).ct_gt(&E256([u64::MAX, 0, 0, 0]))));
+    assert!(bool::from(E256::MIN_S.ct_gt(&E256::MAX_S)));
+    assert!(bool::from(E256::ZERO.ct_lt(&E256::MAX_U)));
+    assert!(!bool::from(E256::MAX_U.ct_gt(&E256::MAX_U)));
+    assert!(bool::from(E8(0x80).ct_gt(&E8(0x7f))));
+    assert_eq!(E256::MIN_S.ct_lt_s(&E256::MAX_S), 1);
+    assert_eq!(E256::MAX_U.ct_lt_s(&E256::ZERO), 1);
+    assert_eq!(E256::conditional_select(&x, &y, 0.into()), x);
+    assert_eq!(E256::conditional_select(&x, &y, 1.into()), y);
+    assert_eq!(E8::conditional_select(&E8(0x80), &E8(0x7f), 1.into()), E8(0x7f));
+    let (mut a, mut b) = (E64::MAX_U, E64(0));
+    E64::conditional_swap(&mut a, &mut b, 1.into());
+    assert_eq!((a, b), (E64(0), E64::MAX_U));
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "zeroize")]
+use eint::{Eint, Secret, E2048, E256, E8};
+use zeroize::Zeroize;
+
+#[test]
+fn test_zeroize() {
+    let mut x = E2048::MAX_U;
+    x.zeroize();
+    assert_eq!(x, E2048::ZERO);
+    let mut x = E8(0x42);
+    x.zeroize();
+    assert_eq!(x, E8(0));
+}
+
+#[test]
+fn test_zeroize_secret() {
+    let a = Secret::new(E256::MAX_U);
+    let b = Secret::from(E256::ONE);
+    assert_eq!(a.ct_add(&b).expose(), &E256::ZERO);
+    assert_eq!(a.ct_sub(&b).expose(), &(E256::MAX_U - E256::ONE));
+    assert_eq!(a.ct_mul(&a).expose(), &E256::ONE);
+    let mut s = a.ct_sub(&b).ct_add(&b);
+    assert_eq!(s.expose(), a.expose());
+    s.zeroize();
+    assert_eq!(s.expose(), &E256::ZERO);
+    let mut p = Secret::new(E8(0x10)).ct_mul(&Secret::new(E8(0x11)));
+    assert_eq!(p.expose(), &E8(0x10));
+    p.zeroize();
+    assert_eq!(p.expose(), &E8(0));
+    assert_eq!(b.ct_lt_u(&a), 1);
+    assert_eq!(a.ct_lt_u(&b), 0);
+    assert_eq!(a.ct_lt_s(&b), 1);
+    assert_eq!(a.ct_eq(&b), 0);
+    assert_eq!(a.ct_eq(&a.clone()), 1);
+    assert_eq!(Secret::ct_select(1, &a, &b).expose(), &E256::MAX_U);
+    assert_eq!(Secret::ct_select(0, &a, &b).expose(), &E256::ONE);
+    let (mut c, mut d) = (a.clone(), b.clone());
+    c.ct_swap(&mut d, 0);
+    assert_eq!((c.expose(), d.expose()), (&E256::MAX_U, &E256::ONE));
+    c.ct_swap(&mut d, 1);
+    assert_eq!((c.expose(), d.expose()), (&E256::ONE, &E256::MAX_U));
+    assert_eq!(format!("{:?}", c), "Secret(..)");
+    c.zeroize();
+    assert_eq!(c.expose(), &E256::ZERO);
+}